@@ -44,3 +44,31 @@ pub fn unhex_to_vec(input: &[u8], out: &mut Vec<u8>) -> Result<usize, DecodeErro
 
     Ok(result)
 }
+
+fn hex_vec_in_place(table: CharTable, buffer: &mut Vec<u8>) -> usize {
+    let len = buffer.len();
+    buffer.resize(required_encode_len(len), 0);
+    arch::hex_in_place(table, buffer, len)
+}
+
+#[inline(always)]
+///Encodes content of `buffer` as upper case hex in place, growing it to fit encoded data.
+pub fn hex_upper_vec_in_place(buffer: &mut Vec<u8>) -> usize {
+    hex_vec_in_place(CHAR_TABLE_UPPER, buffer)
+}
+
+#[inline(always)]
+///Encodes content of `buffer` as lower case hex in place, growing it to fit encoded data.
+pub fn hex_lower_vec_in_place(buffer: &mut Vec<u8>) -> usize {
+    hex_vec_in_place(CHAR_TABLE_LOWER, buffer)
+}
+
+#[inline(always)]
+///Decodes hex-encoded `buffer` in place, truncating it to decoded bytes.
+///
+///On error, vector length remains unchanged, but its content is unspecified.
+pub fn unhex_vec_in_place(buffer: &mut Vec<u8>) -> Result<usize, DecodeError> {
+    let result = arch::unhex_in_place(buffer)?;
+    buffer.truncate(result);
+    Ok(result)
+}
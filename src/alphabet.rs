@@ -0,0 +1,125 @@
+use core::fmt;
+
+use crate::const_fn::{UNHEX_INVALID_CHAR, UNHEX_TABLE};
+use crate::{CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Error of [Alphabet](Alphabet) construction
+pub enum AlphabetError {
+    ///Symbol is not ASCII character
+    NonAscii(u8),
+    ///Symbol is encountered more than once
+    Duplicate(u8),
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonAscii(ch) => fmt.write_fmt(format_args!("Symbol 0x{:02x} is not ASCII", ch)),
+            Self::Duplicate(ch) => fmt.write_fmt(format_args!("Symbol 0x{:02x} is duplicated", ch)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AlphabetError {}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+///Set of 16 distinct ASCII symbols, representing nibble values in order.
+///
+///Reverse table for decoding is derived on construction, which can be done at compile time:
+///
+///```rust
+///use based16::Alphabet;
+///
+///const REVERSED: Alphabet = match Alphabet::new(b"fedcba9876543210") {
+///    Ok(alphabet) => alphabet,
+///    Err(_) => panic!("Invalid alphabet"),
+///};
+///```
+pub struct Alphabet {
+    encode: [u8; 16],
+    decode: [u8; 256],
+    //Decode table matches UNHEX_TABLE, hence SIMD decoding is possible
+    is_hex: bool,
+}
+
+impl Alphabet {
+    ///Lower case hex alphabet, that decodes characters in either case.
+    ///
+    ///Hence it is not equal to `Alphabet::new(b"0123456789abcdef")`, which decodes only lower case.
+    pub const LOWER: Self = Self {
+        encode: *CHAR_TABLE_LOWER,
+        decode: *UNHEX_TABLE,
+        is_hex: true,
+    };
+
+    ///Upper case hex alphabet, that decodes characters in either case.
+    ///
+    ///Hence it is not equal to `Alphabet::new(b"0123456789ABCDEF")`, which decodes only upper case.
+    pub const UPPER: Self = Self {
+        encode: *CHAR_TABLE_UPPER,
+        decode: *UNHEX_TABLE,
+        is_hex: true,
+    };
+
+    ///Creates alphabet out of `symbols`, where each symbol is character for nibble equal to its index.
+    ///
+    ///Decoding is exact, i.e. case of symbols matters.
+    pub const fn new(symbols: &[u8; 16]) -> Result<Self, AlphabetError> {
+        let mut decode = [UNHEX_INVALID_CHAR; 256];
+        let mut idx = 0;
+
+        while idx < symbols.len() {
+            let symbol = symbols[idx];
+            if !symbol.is_ascii() {
+                return Err(AlphabetError::NonAscii(symbol));
+            } else if decode[symbol as usize] != UNHEX_INVALID_CHAR {
+                return Err(AlphabetError::Duplicate(symbol));
+            }
+
+            decode[symbol as usize] = idx as u8;
+            idx += 1;
+        }
+
+        Ok(Self {
+            encode: *symbols,
+            decode,
+            is_hex: false,
+        })
+    }
+
+    #[inline(always)]
+    ///Returns symbols of alphabet, ordered by nibble value
+    pub const fn symbols(&self) -> &[u8; 16] {
+        &self.encode
+    }
+
+    #[inline(always)]
+    ///Returns nibble value of `symbol`, if it is part of alphabet
+    pub const fn nibble(&self, symbol: u8) -> Option<u8> {
+        match self.decode[symbol as usize] {
+            UNHEX_INVALID_CHAR => None,
+            nibble => Some(nibble),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) const fn decode_table(&self) -> &[u8; 256] {
+        &self.decode
+    }
+
+    #[inline(always)]
+    pub(crate) const fn is_hex(&self) -> bool {
+        self.is_hex
+    }
+}
+
+impl fmt::Debug for Alphabet {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbols = unsafe {
+            core::str::from_utf8_unchecked(&self.encode)
+        };
+        fmt.debug_tuple("Alphabet").field(&symbols).finish()
+    }
+}
@@ -1,13 +1,242 @@
 use core::{cmp, mem};
 
-use crate::{CharTable, DecodeError};
-use crate::const_fn::unhex_pair;
+use crate::DecodeError;
+use crate::const_fn::{unhex_pair, unhex_pair_with};
 use crate::{required_encode_len, required_decode_len};
 
+#[cfg(all(target_feature = "sse2", target_arch = "x86"))]
+use core::arch::x86 as sys;
+#[cfg(all(target_feature = "sse2", target_arch = "x86_64"))]
+use core::arch::x86_64 as sys;
+
 const CHUNK_LEN: usize = 16;
 
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+//SIMD encoding computes characters arithmetically, which requires digits and letters to form contiguous ranges
+fn is_ranges(table: &[u8; 16]) -> bool {
+    let mut idx = 1;
+    while idx < table.len() {
+        if idx != 10 && table[idx] != table[idx - 1].wrapping_add(1) {
+            return false;
+        }
+        idx += 1;
+    }
+
+    true
+}
+
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+///Converts `CHUNK_LEN` characters into nibbles, returning them along with bit mask of invalid characters.
+unsafe fn chunk_nibbles(chunk: sys::__m128i) -> (sys::__m128i, i32) {
+    //Reference: http://0x80.pl/notesen/2022-01-17-validating-hex-parse.html
+    let t1 = sys::_mm_add_epi8(chunk, sys::_mm_set1_epi8((0xff - b'9') as i8));
+    let t2 = sys::_mm_subs_epu8(t1, sys::_mm_set1_epi8(6));
+    let t3 = sys::_mm_sub_epi8(t2, sys::_mm_set1_epi8(0xf0u8 as i8));
+    let t4 = sys::_mm_and_si128(chunk, sys::_mm_set1_epi8(0xdfu8 as i8));
+    let t5 = sys::_mm_sub_epi8(t4, sys::_mm_set1_epi8(b'A' as i8));
+    let t6 = sys::_mm_adds_epu8(t5, sys::_mm_set1_epi8(10));
+
+    let nibbles = sys::_mm_min_epu8(t3, t6);
+    let t8 = sys::_mm_adds_epu8(nibbles, sys::_mm_set1_epi8(127-15));
+
+    (nibbles, sys::_mm_movemask_epi8(t8))
+}
+
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+///Decodes `CHUNK_LEN` characters at `input`, storing each byte in lower half of 16bit word.
+///
+///Returns `None` if chunk contains invalid character.
+unsafe fn unhex_chunk(input: *const u8) -> Option<sys::__m128i> {
+    let (nibbles, invalid) = chunk_nibbles(sys::_mm_loadu_si128(input as _));
+
+    if invalid != 0 {
+        return None;
+    }
+
+    let low = sys::_mm_srli_epi16(nibbles, 8);
+    let high = sys::_mm_slli_epi16(nibbles, 4);
+    let result = sys::_mm_or_si128(low, high);
+    Some(sys::_mm_and_si128(result, sys::_mm_set1_epi16(0x00ff)))
+}
+
+#[inline(always)]
+//Returns whether lower and upper case letters are present, or offset of the first invalid character.
+fn scan_scalar(input: &[u8], offset: usize, mut has_lower: bool, mut has_upper: bool) -> Result<(bool, bool), usize> {
+    for (idx, ch) in input.iter().enumerate() {
+        match ch {
+            b'0'..=b'9' => (),
+            b'a'..=b'f' => has_lower = true,
+            b'A'..=b'F' => has_upper = true,
+            _ => return Err(offset + idx),
+        }
+    }
+
+    Ok((has_lower, has_upper))
+}
+
+#[cfg(target_feature = "sse2")]
+pub fn scan(input: &[u8]) -> Result<(bool, bool), usize> {
+    #[inline(always)]
+    //Returns bit mask of characters within `start..start + 6`
+    unsafe fn letters_mask(chunk: sys::__m128i, start: u8) -> i32 {
+        let offsets = sys::_mm_sub_epi8(chunk, sys::_mm_set1_epi8(start as i8));
+        let letters = sys::_mm_cmpeq_epi8(sys::_mm_subs_epu8(offsets, sys::_mm_set1_epi8(5)), sys::_mm_setzero_si128());
+        sys::_mm_movemask_epi8(letters)
+    }
+
+    let mut lower = 0;
+    let mut upper = 0;
+    let mut cursor = 0usize;
+
+    while input.len() - cursor >= CHUNK_LEN {
+        unsafe {
+            let chunk = sys::_mm_loadu_si128(input.as_ptr().add(cursor) as _);
+            let (_, invalid) = chunk_nibbles(chunk);
+            if invalid != 0 {
+                return Err(cursor + invalid.trailing_zeros() as usize);
+            }
+
+            lower |= letters_mask(chunk, b'a');
+            upper |= letters_mask(chunk, b'A');
+        }
+
+        cursor = cursor.saturating_add(CHUNK_LEN);
+    }
+
+    scan_scalar(&input[cursor..], cursor, lower != 0, upper != 0)
+}
+
 #[cfg(not(target_feature = "sse2"))]
-pub fn hex(table: CharTable, input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> usize {
+pub fn scan(input: &[u8]) -> Result<(bool, bool), usize> {
+    scan_scalar(input, 0, false, false)
+}
+
+#[cfg(target_feature = "sse2")]
+pub fn find_byte(input: &[u8], byte: u8) -> Option<usize> {
+    let mut cursor = 0usize;
+
+    unsafe {
+        let needle = sys::_mm_set1_epi8(byte as i8);
+        while input.len() - cursor >= CHUNK_LEN {
+            let chunk = sys::_mm_loadu_si128(input.as_ptr().add(cursor) as _);
+            let found = sys::_mm_movemask_epi8(sys::_mm_cmpeq_epi8(chunk, needle));
+            if found != 0 {
+                return Some(cursor + found.trailing_zeros() as usize);
+            }
+
+            cursor = cursor.saturating_add(CHUNK_LEN);
+        }
+    }
+
+    input[cursor..].iter().position(|ch| *ch == byte).map(|idx| cursor + idx)
+}
+
+#[cfg(not(target_feature = "sse2"))]
+pub fn find_byte(input: &[u8], byte: u8) -> Option<usize> {
+    input.iter().position(|ch| *ch == byte)
+}
+
+//Copies `len` characters, changing case of letters, and returns offset of the first invalid character on error.
+//
+//`input` and `output` may point to the same buffer.
+//Characters before invalid one are processed, while the rest of output is left intact.
+unsafe fn change_case(upper: bool, input: *const u8, output: *mut u8, len: usize) -> Result<(), usize> {
+    const CASE_BIT: u8 = 0x20;
+    let mut cursor = 0usize;
+
+    #[cfg(target_feature = "avx2")]
+    {
+        const AVX_CHUNK_LEN: usize = 32;
+        let case_bit = sys::_mm256_set1_epi8(CASE_BIT as i8);
+
+        while len - cursor >= AVX_CHUNK_LEN {
+            let chunk = sys::_mm256_loadu_si256(input.add(cursor) as _);
+            let digits = sys::_mm256_sub_epi8(chunk, sys::_mm256_set1_epi8(b'0' as i8));
+            let digits = sys::_mm256_cmpeq_epi8(sys::_mm256_subs_epu8(digits, sys::_mm256_set1_epi8(9)), sys::_mm256_setzero_si256());
+            let letters = sys::_mm256_sub_epi8(sys::_mm256_or_si256(chunk, case_bit), sys::_mm256_set1_epi8(b'a' as i8));
+            let letters = sys::_mm256_cmpeq_epi8(sys::_mm256_subs_epu8(letters, sys::_mm256_set1_epi8(5)), sys::_mm256_setzero_si256());
+
+            if sys::_mm256_movemask_epi8(sys::_mm256_or_si256(digits, letters)) != -1 {
+                break;
+            }
+
+            let letters = sys::_mm256_and_si256(letters, case_bit);
+            let result = if upper {
+                sys::_mm256_andnot_si256(letters, chunk)
+            } else {
+                sys::_mm256_or_si256(chunk, letters)
+            };
+            sys::_mm256_storeu_si256(output.add(cursor) as _, result);
+
+            cursor = cursor.saturating_add(AVX_CHUNK_LEN);
+        }
+    }
+
+    #[cfg(target_feature = "sse2")]
+    {
+        let case_bit = sys::_mm_set1_epi8(CASE_BIT as i8);
+
+        while len - cursor >= CHUNK_LEN {
+            let chunk = sys::_mm_loadu_si128(input.add(cursor) as _);
+            let digits = sys::_mm_sub_epi8(chunk, sys::_mm_set1_epi8(b'0' as i8));
+            let digits = sys::_mm_cmpeq_epi8(sys::_mm_subs_epu8(digits, sys::_mm_set1_epi8(9)), sys::_mm_setzero_si128());
+            let letters = sys::_mm_sub_epi8(sys::_mm_or_si128(chunk, case_bit), sys::_mm_set1_epi8(b'a' as i8));
+            let letters = sys::_mm_cmpeq_epi8(sys::_mm_subs_epu8(letters, sys::_mm_set1_epi8(5)), sys::_mm_setzero_si128());
+
+            if sys::_mm_movemask_epi8(sys::_mm_or_si128(digits, letters)) != 0xffff {
+                break;
+            }
+
+            let letters = sys::_mm_and_si128(letters, case_bit);
+            let result = if upper {
+                sys::_mm_andnot_si128(letters, chunk)
+            } else {
+                sys::_mm_or_si128(chunk, letters)
+            };
+            sys::_mm_storeu_si128(output.add(cursor) as _, result);
+
+            cursor = cursor.saturating_add(CHUNK_LEN);
+        }
+    }
+
+    //Remainder and chunk with invalid character
+    while cursor < len {
+        let ch = *input.add(cursor);
+        *output.add(cursor) = match ch {
+            b'0'..=b'9' => ch,
+            b'a'..=b'f' | b'A'..=b'F' => if upper {
+                ch & !CASE_BIT
+            } else {
+                ch | CASE_BIT
+            },
+            _ => return Err(cursor),
+        };
+        cursor = cursor.saturating_add(1);
+    }
+
+    Ok(())
+}
+
+pub fn change_case_in_place(upper: bool, buffer: &mut [u8]) -> Result<(), usize> {
+    let ptr = buffer.as_mut_ptr();
+    unsafe {
+        change_case(upper, ptr, ptr, buffer.len())
+    }
+}
+
+pub fn change_case_copy(upper: bool, input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> Result<usize, usize> {
+    let len = cmp::min(input.len(), output.len());
+    unsafe {
+        change_case(upper, input.as_ptr(), output.as_mut_ptr() as *mut u8, len)?;
+    }
+    Ok(len)
+}
+
+#[cfg(not(target_feature = "sse2"))]
+pub fn hex(table: &[u8; 16], input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> usize {
     let len = if output.len() % 2 != 0 {
         cmp::min(required_encode_len(input.len()), output.len() - 1)
     } else {
@@ -62,12 +291,7 @@ pub fn hex(table: CharTable, input: &[u8], output: &mut [mem::MaybeUninit<u8>])
 }
 
 #[cfg(target_feature = "sse2")]
-pub fn hex(table: CharTable, input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> usize {
-    #[cfg(target_arch = "x86")]
-    use core::arch::x86 as sys;
-    #[cfg(target_arch = "x86_64")]
-    use core::arch::x86_64 as sys;
-
+pub fn hex(table: &[u8; 16], input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> usize {
     let len = if output.len() % 2 != 0 {
         cmp::min(required_encode_len(input.len()), output.len() - 1)
     } else {
@@ -75,11 +299,32 @@ pub fn hex(table: CharTable, input: &[u8], output: &mut [mem::MaybeUninit<u8>])
     };
     let mut written = 0;
 
-    if len >= CHUNK_LEN {
+    //Arbitrary alphabet requires table lookup, which is done via byte shuffle
+    #[cfg(target_feature = "ssse3")]
+    if len >= CHUNK_LEN && !is_ranges(table) {
+        unsafe {
+            let mask = sys::_mm_set1_epi8(0xf);
+            let lookup = sys::_mm_loadu_si128(table.as_ptr() as _);
+
+            loop {
+                let mut value = sys::_mm_loadu_si64(input.as_ptr().add(written / 2));
+                value = sys::_mm_and_si128(sys::_mm_unpacklo_epi8(sys::_mm_srli_epi64(value, 4), value), mask);
+                value = sys::_mm_shuffle_epi8(lookup, value);
+                sys::_mm_storeu_si128(output.as_mut_ptr().add(written) as _, value);
+                written = written.saturating_add(CHUNK_LEN);
+
+                if (len - written) < CHUNK_LEN {
+                    break;
+                }
+            }
+        }
+    }
+
+    if len >= CHUNK_LEN && is_ranges(table) {
         unsafe {
             let mask = sys::_mm_set1_epi8(0xf);
             let mask1 = sys::_mm_set1_epi8(0x9);
-            let mask2 = sys::_mm_set1_epi8((*table.get_unchecked(10) - *table.get_unchecked(0) - 0xA) as _);
+            let mask2 = sys::_mm_set1_epi8(table.get_unchecked(10).wrapping_sub(*table.get_unchecked(0)).wrapping_sub(0xA) as _);
             let mask3 = sys::_mm_set1_epi8(*table.get_unchecked(0) as _);
 
             loop {
@@ -118,56 +363,22 @@ pub fn hex(table: CharTable, input: &[u8], output: &mut [mem::MaybeUninit<u8>])
 pub fn unhex(input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
     const OUTPUT_CHUNK: usize = CHUNK_LEN / 2;
 
-    #[cfg(target_arch = "x86")]
-    use core::arch::x86 as sys;
-    #[cfg(target_arch = "x86_64")]
-    use core::arch::x86_64 as sys;
-
     let len = cmp::min(required_decode_len(input.len()), output.len());
 
-    let mut simd_len = len / CHUNK_LEN;
     let mut cursor = 0usize;
 
-    //Reference: http://0x80.pl/notesen/2022-01-17-validating-hex-parse.html
-    if simd_len > 0 {
-        loop {
-            unsafe {
-                let chunk = sys::_mm_loadu_si128(input.as_ptr().add(cursor.saturating_mul(2)) as _);
-
-                let mut t1 = sys::_mm_add_epi8(chunk, sys::_mm_set1_epi8((0xff - b'9') as i8));
-                let mut t2 = sys::_mm_subs_epu8(t1, sys::_mm_set1_epi8(6));
-                let t3 = sys::_mm_sub_epi8(t2, sys::_mm_set1_epi8(0xf0u8 as i8));
-                let t4 = sys::_mm_and_si128(chunk, sys::_mm_set1_epi8(0xdfu8 as i8));
-                let t5 = sys::_mm_sub_epi8(t4, sys::_mm_set1_epi8(b'A' as i8));
-                let t6 = sys::_mm_adds_epu8(t5, sys::_mm_set1_epi8(10));
-
-                let nibbles = sys::_mm_min_epu8(t3, t6);
-                let t8 = sys::_mm_adds_epu8(nibbles, sys::_mm_set1_epi8(127-15));
-
-                if sys::_mm_movemask_epi8(t8) > 0 {
-                    return Err(DecodeError::unexpected_char(input[0]));
-                }
-
-                //convert to actual binary output
-                let result = {
-                    let low = sys::_mm_srli_epi16(nibbles, 8);
-                    let high = sys::_mm_slli_epi16(nibbles, 4);
-                    t1 = sys::_mm_or_si128(low, high);
-                    t2 = sys::_mm_and_si128(t1, sys::_mm_set1_epi16(0x00ff));
-                    let t3: [u64; 2] = core::mem::transmute(sys::_mm_packus_epi16(t2, sys::_mm_setzero_si128()));
-                    t3[0]
-                };
-
-                core::ptr::write_unaligned(output.as_mut_ptr().add(cursor) as *mut u64, result)
-            }
-
-            cursor = cursor.saturating_add(OUTPUT_CHUNK);
-            if simd_len == 0 {
-                break;
-            } else {
-                simd_len = simd_len.saturating_sub(1);
-            }
+    while len - cursor >= OUTPUT_CHUNK {
+        unsafe {
+            let words = match unhex_chunk(input.as_ptr().add(cursor.saturating_mul(2))) {
+                Some(words) => words,
+                //Scalar loop reports exact invalid character
+                None => break,
+            };
+            let result = sys::_mm_packus_epi16(words, sys::_mm_setzero_si128());
+            sys::_mm_storel_epi64(output.as_mut_ptr().add(cursor) as _, result);
         }
+
+        cursor = cursor.saturating_add(OUTPUT_CHUNK);
     }
 
     while cursor < len {
@@ -229,3 +440,381 @@ pub fn unhex(input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> Result<usize,
 
     Ok(written)
 }
+
+#[cfg(not(target_feature = "sse2"))]
+pub fn hex_rev(table: &[u8; 16], input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> usize {
+    let len = if output.len() % 2 != 0 {
+        cmp::min(required_encode_len(input.len()), output.len() - 1)
+    } else {
+        cmp::min(required_encode_len(input.len()), output.len())
+    };
+
+    //Points past the last byte that is not yet encoded
+    let mut cursor = unsafe {
+        input.as_ptr().add(input.len())
+    };
+    let mut written = 0;
+
+    macro_rules! process_byte {
+        ($offset:expr) => {
+            unsafe {
+                let byt = cursor.sub($offset / 2 + 1).read();
+                let dst = output.as_mut_ptr().add(written + $offset) as *mut u8;
+
+                *dst = table[(byt.wrapping_shr(4) & 0xf) as usize];
+                *dst.add(1) = table[(byt & 0xf) as usize];
+            }
+        };
+    }
+
+    macro_rules! on_proceess_end {
+        ($chunk_size:expr) => {
+            unsafe {
+                cursor = cursor.sub($chunk_size / 2);
+            }
+            written = written.saturating_add($chunk_size);
+        };
+    }
+
+    for _ in 0..len / CHUNK_LEN {
+        process_byte!(0);
+        process_byte!(2);
+        process_byte!(4);
+        process_byte!(6);
+        process_byte!(8);
+        process_byte!(10);
+        process_byte!(12);
+        process_byte!(14);
+
+        on_proceess_end!(CHUNK_LEN);
+    }
+
+    while written < len {
+        process_byte!(0);
+
+        on_proceess_end!(2);
+    }
+
+    written
+}
+
+#[cfg(target_feature = "sse2")]
+pub fn hex_rev(table: &[u8; 16], input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> usize {
+    let len = if output.len() % 2 != 0 {
+        cmp::min(required_encode_len(input.len()), output.len() - 1)
+    } else {
+        cmp::min(required_encode_len(input.len()), output.len())
+    };
+    let mut written = 0;
+
+    if len >= CHUNK_LEN && is_ranges(table) {
+        unsafe {
+            let mask = sys::_mm_set1_epi8(0xf);
+            let mask1 = sys::_mm_set1_epi8(0x9);
+            let mask2 = sys::_mm_set1_epi8(table.get_unchecked(10).wrapping_sub(*table.get_unchecked(0)).wrapping_sub(0xA) as _);
+            let mask3 = sys::_mm_set1_epi8(*table.get_unchecked(0) as _);
+
+            loop {
+                let mut value = sys::_mm_loadu_si64(input.as_ptr().add(input.len() - written / 2 - CHUNK_LEN / 2));
+                value = sys::_mm_and_si128(sys::_mm_unpacklo_epi8(sys::_mm_srli_epi64(value, 4), value), mask);
+                value = sys::_mm_add_epi8(
+                    sys::_mm_add_epi8(value, mask3),
+                    sys::_mm_and_si128(sys::_mm_cmpgt_epi8(value, mask1), mask2)
+                );
+                //Reverse order of character pairs (16bit words)
+                value = sys::_mm_shufflelo_epi16(value, 0x1b);
+                value = sys::_mm_shufflehi_epi16(value, 0x1b);
+                value = sys::_mm_shuffle_epi32(value, 0x4e);
+                sys::_mm_storeu_si128(output.as_mut_ptr().add(written) as _, value);
+                written = written.saturating_add(CHUNK_LEN);
+
+                if (len - written) < CHUNK_LEN {
+                    break;
+                }
+            }
+        }
+    }
+
+    while written < len {
+        unsafe {
+            let byt = *(input.as_ptr().add(input.len() - written / 2 - 1));
+            let dst = output.as_mut_ptr().add(written) as *mut u8;
+
+            *dst = table[(byt.wrapping_shr(4) & 0xf) as usize];
+            *dst.add(1) = table[(byt & 0xf) as usize];
+        }
+
+        written = written.saturating_add(2);
+    }
+
+    written
+}
+
+#[cfg(target_feature = "sse2")]
+pub fn unhex_rev(input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
+    const OUTPUT_CHUNK: usize = CHUNK_LEN / 2;
+
+    let pairs = required_decode_len(input.len());
+    let len = cmp::min(pairs, output.len());
+    let mut cursor = 0usize;
+
+    while len - cursor >= OUTPUT_CHUNK {
+        let offset = (pairs - cursor - OUTPUT_CHUNK).saturating_mul(2);
+        unsafe {
+            let mut words = match unhex_chunk(input.as_ptr().add(offset)) {
+                Some(words) => words,
+                None => break,
+            };
+            //Reverse order of decoded bytes before packing
+            words = sys::_mm_shufflelo_epi16(words, 0x1b);
+            words = sys::_mm_shufflehi_epi16(words, 0x1b);
+            words = sys::_mm_shuffle_epi32(words, 0x4e);
+            let result = sys::_mm_packus_epi16(words, sys::_mm_setzero_si128());
+            sys::_mm_storel_epi64(output.as_mut_ptr().add(cursor) as _, result);
+        }
+
+        cursor = cursor.saturating_add(OUTPUT_CHUNK);
+    }
+
+    //Handles remainder or reports invalid character found by SIMD check
+    while cursor < len {
+        let chunk = unsafe {
+            *(input.as_ptr().add((pairs - cursor - 1).saturating_mul(2)) as *const [u8; 2])
+        };
+        let char = unhex_pair(chunk)?;
+        output[cursor] = mem::MaybeUninit::new(char);
+        cursor = cursor.saturating_add(1);
+    }
+
+    Ok(cursor)
+}
+
+#[cfg(not(target_feature = "sse2"))]
+pub fn unhex_rev(input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
+    let pairs = required_decode_len(input.len());
+    let len = cmp::min(pairs, output.len());
+
+    let mut written = 0usize;
+    macro_rules! process_byte {
+        ($offset:expr) => {
+            unsafe {
+                let chunk = *(input.as_ptr().add((pairs - written - $offset / 2 - 1) * 2) as *const [u8; 2]);
+                let ch = unhex_pair(chunk)?;
+                *output.get_unchecked_mut(written + ($offset / 2)) = mem::MaybeUninit::new(ch);
+            }
+        };
+    }
+
+    macro_rules! on_proceess_end {
+        ($chunk_size:expr) => {
+            written = written.saturating_add($chunk_size / 2);
+        };
+    }
+
+    for _ in 0..len / CHUNK_LEN {
+        process_byte!(0);
+        process_byte!(2);
+        process_byte!(4);
+        process_byte!(6);
+        process_byte!(8);
+        process_byte!(10);
+        process_byte!(12);
+        process_byte!(14);
+
+        on_proceess_end!(CHUNK_LEN);
+    }
+
+    while written < len {
+        process_byte!(0);
+
+        on_proceess_end!(2);
+    }
+
+    Ok(written)
+}
+
+#[cfg(target_feature = "sse2")]
+pub fn unhex_in_place(buffer: &mut [u8]) -> Result<usize, DecodeError> {
+    const OUTPUT_CHUNK: usize = CHUNK_LEN / 2;
+
+    let len = required_decode_len(buffer.len());
+    //Work via raw pointer as reads and writes alias.
+    //Write cursor always trails read cursor: chunk at `cursor * 2` is fully loaded before storing at `cursor`
+    let ptr = buffer.as_mut_ptr();
+    let mut cursor = 0usize;
+
+    while len - cursor >= OUTPUT_CHUNK {
+        unsafe {
+            let words = match unhex_chunk(ptr.add(cursor.saturating_mul(2))) {
+                Some(words) => words,
+                None => break,
+            };
+            let result = sys::_mm_packus_epi16(words, sys::_mm_setzero_si128());
+            sys::_mm_storel_epi64(ptr.add(cursor) as _, result);
+        }
+
+        cursor = cursor.saturating_add(OUTPUT_CHUNK);
+    }
+
+    while cursor < len {
+        unsafe {
+            let chunk = *(ptr.add(cursor.saturating_mul(2)) as *const [u8; 2]);
+            *ptr.add(cursor) = unhex_pair(chunk)?;
+        }
+        cursor = cursor.saturating_add(1);
+    }
+
+    Ok(cursor)
+}
+
+#[cfg(not(target_feature = "sse2"))]
+pub fn unhex_in_place(buffer: &mut [u8]) -> Result<usize, DecodeError> {
+    let len = required_decode_len(buffer.len());
+    //Write cursor always trails read cursor, so each pair is read before its position is overwritten
+    let ptr = buffer.as_mut_ptr();
+
+    let mut written = 0usize;
+    macro_rules! process_byte {
+        ($offset:expr) => {
+            unsafe {
+                let chunk = *(ptr.add(written.saturating_mul(2) + $offset) as *const [u8; 2]);
+                *ptr.add(written + ($offset / 2)) = unhex_pair(chunk)?;
+            }
+        };
+    }
+
+    macro_rules! on_proceess_end {
+        ($chunk_size:expr) => {
+            written = written.saturating_add($chunk_size / 2);
+        };
+    }
+
+    for _ in 0..len / CHUNK_LEN {
+        process_byte!(0);
+        process_byte!(2);
+        process_byte!(4);
+        process_byte!(6);
+        process_byte!(8);
+        process_byte!(10);
+        process_byte!(12);
+        process_byte!(14);
+
+        on_proceess_end!(CHUNK_LEN);
+    }
+
+    while written < len {
+        process_byte!(0);
+
+        on_proceess_end!(2);
+    }
+
+    Ok(written)
+}
+
+#[cfg(target_feature = "sse2")]
+pub fn hex_in_place(table: &[u8; 16], buffer: &mut [u8], len: usize) -> usize {
+    const INPUT_CHUNK: usize = CHUNK_LEN / 2;
+
+    let required_len = required_encode_len(len);
+    assert!(required_len <= buffer.len(), "buffer cannot fit encoded data");
+
+    //Work via raw pointer as reads and writes alias.
+    //Expanding from the end: chunk at `cursor` is loaded before storing at `cursor * 2`, which never precedes `cursor`
+    let ptr = buffer.as_mut_ptr();
+    let mut cursor = len;
+
+    if cursor >= INPUT_CHUNK && is_ranges(table) {
+        unsafe {
+            let mask = sys::_mm_set1_epi8(0xf);
+            let mask1 = sys::_mm_set1_epi8(0x9);
+            let mask2 = sys::_mm_set1_epi8(table.get_unchecked(10).wrapping_sub(*table.get_unchecked(0)).wrapping_sub(0xA) as _);
+            let mask3 = sys::_mm_set1_epi8(*table.get_unchecked(0) as _);
+
+            while cursor >= INPUT_CHUNK {
+                cursor -= INPUT_CHUNK;
+
+                let mut value = sys::_mm_loadu_si64(ptr.add(cursor));
+                value = sys::_mm_and_si128(sys::_mm_unpacklo_epi8(sys::_mm_srli_epi64(value, 4), value), mask);
+                value = sys::_mm_add_epi8(
+                    sys::_mm_add_epi8(value, mask3),
+                    sys::_mm_and_si128(sys::_mm_cmpgt_epi8(value, mask1), mask2)
+                );
+                sys::_mm_storeu_si128(ptr.add(cursor.saturating_mul(2)) as _, value);
+            }
+        }
+    }
+
+    while cursor > 0 {
+        cursor -= 1;
+
+        unsafe {
+            let byt = *ptr.add(cursor);
+            let dst = ptr.add(cursor.saturating_mul(2));
+
+            *dst = table[(byt.wrapping_shr(4) & 0xf) as usize];
+            *dst.add(1) = table[(byt & 0xf) as usize];
+        }
+    }
+
+    required_len
+}
+
+#[cfg(not(target_feature = "sse2"))]
+pub fn hex_in_place(table: &[u8; 16], buffer: &mut [u8], len: usize) -> usize {
+    let required_len = required_encode_len(len);
+    assert!(required_len <= buffer.len(), "buffer cannot fit encoded data");
+
+    //Expanding from the end: byte at `cursor` is read before storing at `cursor * 2`, which never precedes `cursor`
+    let ptr = buffer.as_mut_ptr();
+    let mut cursor = len;
+
+    macro_rules! process_byte {
+        ($offset:expr) => {
+            unsafe {
+                let idx = cursor - $offset - 1;
+                let byt = *ptr.add(idx);
+                let dst = ptr.add(idx * 2);
+
+                *dst = table[(byt.wrapping_shr(4) & 0xf) as usize];
+                *dst.add(1) = table[(byt & 0xf) as usize];
+            }
+        };
+    }
+
+    macro_rules! on_proceess_end {
+        ($chunk_size:expr) => {
+            cursor -= $chunk_size / 2;
+        };
+    }
+
+    for _ in 0..required_len / CHUNK_LEN {
+        process_byte!(0);
+        process_byte!(1);
+        process_byte!(2);
+        process_byte!(3);
+        process_byte!(4);
+        process_byte!(5);
+        process_byte!(6);
+        process_byte!(7);
+
+        on_proceess_end!(CHUNK_LEN);
+    }
+
+    while cursor > 0 {
+        process_byte!(0);
+
+        on_proceess_end!(2);
+    }
+
+    required_len
+}
+
+pub fn unhex_with_table(table: &[u8; 256], input: &[u8], output: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
+    let len = cmp::min(required_decode_len(input.len()), output.len());
+
+    for (pair, out) in input.chunks_exact(2).zip(output[..len].iter_mut()) {
+        *out = mem::MaybeUninit::new(unhex_pair_with(table, [pair[0], pair[1]])?);
+    }
+
+    Ok(len)
+}
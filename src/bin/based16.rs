@@ -0,0 +1,425 @@
+//!HEX encoder/decoder utility
+
+use std::io::{self, Read, Write};
+use std::{env, fmt, fs, mem, process};
+
+const USAGE: &str = "Usage: based16 [OPTIONS] [FILE]...
+
+Encodes FILE(s) or standard input as HEX, writing result to standard output.
+With no FILE, or when FILE is -, reads standard input.
+When decoding, characters other than HEX digits and line endings are rejected.
+Data decoded before failure is already written.
+
+Options:
+  -d, --decode             Decode HEX input instead
+  -u, --upper              Encode using upper case characters
+  -p, --prefix             Encode: prefix each line with 0x. Decode: skip 0x at the start of each line
+  -w, --wrap <COLS>        Encode: wrap lines after COLS characters (0 disables wrapping, default)
+  -i, --ignore-whitespace  Decode: skip whitespace characters
+  -s, --strict             Decode: fail on odd number of characters or carriage return outside of line ending,
+                           instead of skipping them
+  -h, --help               Prints this help
+";
+
+const READ_BUFFER_LEN: usize = 64 * 1024;
+
+struct Args {
+    decode: bool,
+    upper: bool,
+    prefix: bool,
+    wrap: usize,
+    ignore_whitespace: bool,
+    strict: bool,
+    files: Vec<String>,
+}
+
+impl Args {
+    fn parse() -> Result<Self, String> {
+        let mut result = Args {
+            decode: false,
+            upper: false,
+            prefix: false,
+            wrap: 0,
+            ignore_whitespace: false,
+            strict: false,
+            files: Vec::new(),
+        };
+
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-d" | "--decode" => result.decode = true,
+                "-u" | "--upper" => result.upper = true,
+                "-p" | "--prefix" => result.prefix = true,
+                "-i" | "--ignore-whitespace" => result.ignore_whitespace = true,
+                "-s" | "--strict" => result.strict = true,
+                "-h" | "--help" => {
+                    print!("{}", USAGE);
+                    process::exit(0);
+                },
+                "-w" | "--wrap" => match args.next() {
+                    Some(cols) => result.wrap = parse_wrap(&cols)?,
+                    None => return Err(format!("{} requires value", arg)),
+                },
+                "-" => result.files.push(arg),
+                arg => if let Some(cols) = arg.strip_prefix("--wrap=") {
+                    result.wrap = parse_wrap(cols)?;
+                } else if arg.starts_with('-') {
+                    return Err(format!("Unknown option '{}'", arg));
+                } else {
+                    result.files.push(arg.to_owned());
+                },
+            }
+        }
+
+        if result.files.is_empty() {
+            result.files.push("-".to_owned());
+        }
+
+        Ok(result)
+    }
+}
+
+fn parse_wrap(cols: &str) -> Result<usize, String> {
+    cols.parse().map_err(|_| format!("Invalid number of columns '{}'", cols))
+}
+
+fn display_name(file: &str) -> &str {
+    match file {
+        "-" => "<stdin>",
+        file => file,
+    }
+}
+
+enum Error {
+    Io(String, io::Error),
+    InvalidChar {
+        file: String,
+        ch: u8,
+        offset: u64,
+    },
+    OddLength {
+        file: String,
+        offset: u64,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(file, error) => write!(fmt, "{}: {}", display_name(file), error),
+            Error::InvalidChar { file, ch, offset } => write!(fmt, "{}: Invalid character 0x{:02x} at offset {}", display_name(file), ch, offset),
+            Error::OddLength { file, offset } => write!(fmt, "{}: Odd number of HEX characters, character at offset {} has no pair", display_name(file), offset),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    #[inline(always)]
+    fn from(error: io::Error) -> Self {
+        Error::Io("<stdout>".to_owned(), error)
+    }
+}
+
+struct Encoder {
+    upper: bool,
+    prefix: bool,
+    wrap: usize,
+    column: usize,
+    is_empty: bool,
+    buffer: Vec<mem::MaybeUninit<u8>>,
+}
+
+impl Encoder {
+    fn new(args: &Args) -> Self {
+        Self {
+            upper: args.upper,
+            prefix: args.prefix,
+            wrap: args.wrap,
+            column: 0,
+            is_empty: true,
+            buffer: vec![mem::MaybeUninit::uninit(); based16::required_encode_len(READ_BUFFER_LEN)],
+        }
+    }
+
+    fn feed<W: Write>(&mut self, input: &[u8], out: &mut W) -> io::Result<()> {
+        let len = if self.upper {
+            based16::hex_upper(input, &mut self.buffer)
+        } else {
+            based16::hex_lower(input, &mut self.buffer)
+        };
+        let mut encoded = unsafe {
+            std::slice::from_raw_parts(self.buffer.as_ptr() as *const u8, len)
+        };
+
+        while !encoded.is_empty() {
+            if self.column == 0 && self.prefix {
+                out.write_all(b"0x")?;
+            }
+
+            let len = match self.wrap {
+                0 => encoded.len(),
+                wrap => std::cmp::min(wrap - self.column, encoded.len()),
+            };
+            out.write_all(&encoded[..len])?;
+            encoded = &encoded[len..];
+            self.is_empty = false;
+            self.column += len;
+
+            if self.column == self.wrap {
+                out.write_all(b"\n")?;
+                self.column = 0;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish<W: Write>(&mut self, out: &mut W) -> io::Result<()> {
+        if self.column > 0 || (self.wrap == 0 && !self.is_empty) {
+            out.write_all(b"\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn unhex<'a>(input: &[u8], buffer: &'a mut [mem::MaybeUninit<u8>]) -> Result<&'a [u8], based16::DecodeError> {
+    let len = based16::unhex(input, buffer)?;
+    Ok(unsafe {
+        std::slice::from_raw_parts(buffer.as_ptr() as *const u8, len)
+    })
+}
+
+struct Decoder {
+    prefix: bool,
+    ignore_whitespace: bool,
+    strict: bool,
+    //Current file and offset of the next byte within it
+    file: String,
+    offset: u64,
+    //Offset of `\r`, that must be followed by `\n` in strict mode
+    cr_offset: Option<u64>,
+    is_line_start: bool,
+    //Offset of `0` encountered at the start of line, when it is not yet known whether it is prefix
+    prefix_zero: Option<u64>,
+    //File and offset of the last HEX character, reported if it is left without pair
+    nibble_file: String,
+    nibble_offset: u64,
+    //Set when file changes, so that name is copied into `nibble_file` only once per file
+    is_nibble_file_outdated: bool,
+    //Valid HEX characters pending decoding
+    hex: Vec<u8>,
+    buffer: Vec<mem::MaybeUninit<u8>>,
+}
+
+impl Decoder {
+    fn new(args: &Args) -> Self {
+        Self {
+            prefix: args.prefix,
+            ignore_whitespace: args.ignore_whitespace,
+            strict: args.strict,
+            file: String::new(),
+            offset: 0,
+            cr_offset: None,
+            is_line_start: true,
+            prefix_zero: None,
+            nibble_file: String::new(),
+            nibble_offset: 0,
+            is_nibble_file_outdated: true,
+            hex: Vec::with_capacity(READ_BUFFER_LEN + 1),
+            buffer: vec![mem::MaybeUninit::uninit(); based16::required_decode_len(READ_BUFFER_LEN) + 1],
+        }
+    }
+
+    fn start_file(&mut self, file: &str) {
+        self.file.clear();
+        self.file.push_str(file);
+        self.offset = 0;
+        self.is_nibble_file_outdated = true;
+    }
+
+    fn end_file(&mut self) -> Result<(), Error> {
+        //Prefix cannot span files
+        if let Some(offset) = self.prefix_zero.take() {
+            self.push_hex(b'0', offset);
+        }
+
+        match self.cr_offset.take() {
+            Some(offset) => Err(self.invalid_char(b'\r', offset)),
+            None => Ok(()),
+        }
+    }
+
+    fn invalid_char(&self, ch: u8, offset: u64) -> Error {
+        Error::InvalidChar {
+            file: self.file.clone(),
+            ch,
+            offset,
+        }
+    }
+
+    fn push_hex(&mut self, ch: u8, offset: u64) {
+        if mem::take(&mut self.is_nibble_file_outdated) {
+            self.nibble_file.clear();
+            self.nibble_file.push_str(&self.file);
+        }
+        self.nibble_offset = offset;
+        self.hex.push(ch);
+    }
+
+    fn filter(&mut self, input: &[u8]) -> Result<(), Error> {
+        for &ch in input {
+            let offset = self.offset;
+            self.offset += 1;
+
+            if let Some(cr_offset) = self.cr_offset.take() {
+                if ch != b'\n' {
+                    return Err(self.invalid_char(b'\r', cr_offset));
+                }
+            }
+
+            if let Some(zero_offset) = self.prefix_zero.take() {
+                if ch == b'x' || ch == b'X' {
+                    continue;
+                }
+                self.push_hex(b'0', zero_offset);
+            }
+
+            if ch.is_ascii_whitespace() && self.ignore_whitespace {
+                //Skipped whitespace at the start of line doesn't prevent prefix detection
+                self.is_line_start |= ch == b'\n';
+                continue;
+            }
+
+            let is_line_start = mem::replace(&mut self.is_line_start, ch == b'\n');
+            if ch.is_ascii_hexdigit() {
+                if self.prefix && is_line_start && ch == b'0' {
+                    self.prefix_zero = Some(offset);
+                } else {
+                    self.push_hex(ch, offset);
+                }
+            } else {
+                match ch {
+                    b'\n' => (),
+                    b'\r' if self.strict => self.cr_offset = Some(offset),
+                    b'\r' => (),
+                    ch => return Err(self.invalid_char(ch, offset)),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn flush_pairs<W: Write>(&mut self, out: &mut W) -> Result<(), Error> {
+        let len = self.hex.len() - self.hex.len() % 2;
+        //Characters are validated by filter
+        let decoded = unhex(&self.hex[..len], &mut self.buffer).expect("valid HEX");
+        out.write_all(decoded)?;
+        self.hex.drain(..len);
+        Ok(())
+    }
+
+    fn feed<W: Write>(&mut self, input: &[u8], out: &mut W) -> Result<(), Error> {
+        let result = self.decode(input, out);
+        //Pairs preceding invalid character are written too
+        self.flush_pairs(out)?;
+        result
+    }
+
+    fn decode<W: Write>(&mut self, mut input: &[u8], out: &mut W) -> Result<(), Error> {
+        //Complete pair split across reads first
+        if !self.hex.is_empty() && !input.is_empty() {
+            self.filter(&input[..1])?;
+            input = &input[1..];
+        }
+
+        //Fast path: decode straight from input, falling back to character filtering on first invalid character.
+        //Pending carriage return requires line feed to follow, hence it is checked by filter
+        if self.hex.is_empty() && !self.prefix && self.prefix_zero.is_none() && self.cr_offset.is_none() {
+            let len = input.len() - input.len() % 2;
+            if let Ok(decoded) = unhex(&input[..len], &mut self.buffer) {
+                out.write_all(decoded)?;
+                self.offset += len as u64;
+                self.is_line_start = false;
+                input = &input[len..];
+            }
+        }
+
+        self.filter(input)
+    }
+
+    fn finish<W: Write>(&mut self, out: &mut W) -> Result<(), Error> {
+        //Only trailing pairs are still pending, as the rest of data is decoded on the fly
+        if self.hex.len() % 2 == 1 && self.strict {
+            return Err(Error::OddLength {
+                file: self.nibble_file.clone(),
+                offset: self.nibble_offset,
+            });
+        }
+
+        self.flush_pairs(out)
+    }
+}
+
+fn run(args: &Args) -> Result<(), Error> {
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+    let mut input_buffer = vec![0u8; READ_BUFFER_LEN];
+    let mut encoder = Encoder::new(args);
+    let mut decoder = Decoder::new(args);
+
+    for file in args.files.iter() {
+        decoder.start_file(file);
+        let mut input: Box<dyn Read> = match file.as_str() {
+            "-" => Box::new(io::stdin()),
+            path => match fs::File::open(path) {
+                Ok(file) => Box::new(file),
+                Err(error) => return Err(Error::Io(path.to_owned(), error)),
+            },
+        };
+
+        loop {
+            let len = match input.read(&mut input_buffer) {
+                Ok(0) => break,
+                Ok(len) => len,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(Error::Io(file.clone(), error)),
+            };
+
+            if args.decode {
+                decoder.feed(&input_buffer[..len], &mut out)?;
+            } else {
+                encoder.feed(&input_buffer[..len], &mut out)?;
+            }
+        }
+
+        if args.decode {
+            decoder.end_file()?;
+        }
+    }
+
+    if args.decode {
+        decoder.finish(&mut out)?;
+    } else {
+        encoder.finish(&mut out)?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+fn main() {
+    let args = match Args::parse() {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(error) = run(&args) {
+        eprintln!("based16: {}", error);
+        process::exit(1);
+    }
+}
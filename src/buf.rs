@@ -0,0 +1,118 @@
+use core::mem;
+
+use bytes::{Buf, BufMut};
+
+use crate::{arch, CharTable, DecodeError, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+use crate::{dec2hex, unhex_nibble, unhex_pair, required_encode_len};
+
+pub(crate) fn hex_to_buf_mut<B: BufMut + ?Sized>(table: CharTable, mut input: &[u8], out: &mut B) -> usize {
+    let required_len = required_encode_len(input.len());
+    assert!(out.remaining_mut() >= required_len, "BufMut cannot fit encoded data");
+
+    while !input.is_empty() {
+        let chunk = out.chunk_mut();
+        let chunk: &mut [mem::MaybeUninit<u8>] = unsafe {
+            chunk.as_uninit_slice_mut()
+        };
+        let written = arch::hex(table, input, chunk);
+
+        if written == 0 {
+            //Pair is split across chunks
+            out.put_slice(dec2hex(table, input[0]).as_bytes());
+            input = &input[1..];
+        } else {
+            unsafe {
+                out.advance_mut(written);
+            }
+            input = &input[written / 2..];
+        }
+    }
+
+    required_len
+}
+
+#[inline(always)]
+///Writes upper case hex into `out`, encoding directly into its uninitialized chunks.
+///
+///## Panics
+///
+///If `out` cannot fit encoded data.
+pub fn hex_upper_to_buf_mut<B: BufMut + ?Sized>(input: &[u8], out: &mut B) -> usize {
+    hex_to_buf_mut(CHAR_TABLE_UPPER, input, out)
+}
+
+#[inline(always)]
+///Writes lower case hex into `out`, encoding directly into its uninitialized chunks.
+///
+///## Panics
+///
+///If `out` cannot fit encoded data.
+pub fn hex_lower_to_buf_mut<B: BufMut + ?Sized>(input: &[u8], out: &mut B) -> usize {
+    hex_to_buf_mut(CHAR_TABLE_LOWER, input, out)
+}
+
+#[cfg(feature = "std")]
+//Reads pair split across first two chunks without consuming it
+fn peek_split_pair<I: Buf + ?Sized>(input: &I) -> Option<[u8; 2]> {
+    let mut chunks = [std::io::IoSlice::new(&[]); 2];
+    match input.chunks_vectored(&mut chunks) {
+        2 => Some([*chunks[0].first()?, *chunks[1].first()?]),
+        _ => None,
+    }
+}
+
+#[cfg(not(feature = "std"))]
+#[inline(always)]
+fn peek_split_pair<I: Buf + ?Sized>(_: &I) -> Option<[u8; 2]> {
+    None
+}
+
+///Decodes hex from `input` into `out`, walking over each contiguous chunk.
+///
+///Decoding stops when either `input` has less than 2 characters or `out` is full.
+///Trailing character of odd length input is left in `input`.
+///
+///On error, `input` and `out` are advanced only past chunks that were successfully decoded.
+///Pair split across chunks is consumed only once decoded, unless `std` feature is disabled or `input` doesn't expose
+///following chunk via `chunks_vectored`, in which case its first character is consumed if second one is invalid.
+pub fn unhex_from_buf<I: Buf + ?Sized, O: BufMut + ?Sized>(input: &mut I, out: &mut O) -> Result<usize, DecodeError> {
+    let mut result = 0usize;
+
+    while input.remaining() >= 2 && out.has_remaining_mut() {
+        let chunk = input.chunk();
+
+        if chunk.len() < 2 {
+            //Pair is split across chunks
+            let byte = match peek_split_pair(input) {
+                Some(pair) => {
+                    let byte = unhex_pair(pair)?;
+                    input.advance(2);
+                    byte
+                },
+                None => {
+                    unhex_nibble(chunk[0])?;
+                    let high = input.get_u8();
+                    let byte = unhex_pair([high, input.chunk()[0]])?;
+                    input.advance(1);
+                    byte
+                },
+            };
+            out.put_u8(byte);
+            result = result.saturating_add(1);
+            continue;
+        }
+
+        let out_chunk = out.chunk_mut();
+        let out_chunk: &mut [mem::MaybeUninit<u8>] = unsafe {
+            out_chunk.as_uninit_slice_mut()
+        };
+        let written = arch::unhex(chunk, out_chunk)?;
+        unsafe {
+            out.advance_mut(written);
+        }
+        input.advance(required_encode_len(written));
+        result = result.saturating_add(written);
+    }
+
+    Ok(result)
+}
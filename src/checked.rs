@@ -0,0 +1,96 @@
+use core::{fmt, mem};
+
+use crate::{arch, validate, DecodeErrorAt, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Output buffer cannot fit result
+pub struct OutputTooSmall {
+    ///Length required to fit result
+    pub required: usize,
+    ///Length of output buffer
+    pub actual: usize,
+}
+
+impl fmt::Display for OutputTooSmall {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("Output of {} bytes is too small, required {}", self.actual, self.required))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutputTooSmall {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Error of [try_unhex](try_unhex)
+pub enum TryDecodeError {
+    ///Input has odd length, which is specified
+    OddLength(usize),
+    ///Output buffer cannot fit decoded bytes
+    OutputTooSmall(OutputTooSmall),
+    ///Invalid character encountered
+    InvalidChar(DecodeErrorAt),
+}
+
+impl fmt::Display for TryDecodeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength(len) => fmt.write_fmt(format_args!("Input has odd length {}", len)),
+            Self::OutputTooSmall(error) => fmt::Display::fmt(error, fmt),
+            Self::InvalidChar(error) => fmt::Display::fmt(error, fmt),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryDecodeError {}
+
+#[inline(always)]
+///Length required to HEX encode, returning `None` on overflow
+pub const fn checked_required_encode_len(len: usize) -> Option<usize> {
+    len.checked_mul(2)
+}
+
+#[inline]
+fn try_hex(table: &[u8; 16], input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, OutputTooSmall> {
+    match checked_required_encode_len(input.len()) {
+        Some(required) if required <= out.len() => Ok(arch::hex(table, input, out)),
+        required => Err(OutputTooSmall {
+            required: required.unwrap_or(usize::MAX),
+            actual: out.len(),
+        }),
+    }
+}
+
+#[inline(always)]
+///Writes upper case hex into `out`, failing if it cannot fit whole `input`
+pub fn try_hex_upper(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, OutputTooSmall> {
+    try_hex(CHAR_TABLE_UPPER, input, out)
+}
+
+#[inline(always)]
+///Writes lower case hex into `out`, failing if it cannot fit whole `input`
+pub fn try_hex_lower(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, OutputTooSmall> {
+    try_hex(CHAR_TABLE_LOWER, input, out)
+}
+
+///Decodes hex-encoded `input` into `out`, failing if input has odd length or output cannot fit it.
+///
+///Lengths are checked before decoding, hence nothing is written on length error.
+pub fn try_unhex(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, TryDecodeError> {
+    if input.len() % 2 != 0 {
+        return Err(TryDecodeError::OddLength(input.len()));
+    }
+
+    let required = input.len() / 2;
+    if required > out.len() {
+        return Err(TryDecodeError::OutputTooSmall(OutputTooSmall {
+            required,
+            actual: out.len(),
+        }));
+    }
+
+    match arch::unhex(input, out) {
+        Ok(len) => Ok(len),
+        Err(_) => Err(TryDecodeError::InvalidChar(validate(input).expect_err("to have invalid character"))),
+    }
+}
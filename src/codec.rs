@@ -0,0 +1,231 @@
+use core::{cmp, fmt};
+use std::io;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{CharTable, DecodeError, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+use crate::{required_encode_len, required_decode_len};
+
+#[derive(Debug)]
+///Error of [HexLinesCodec](HexLinesCodec)
+///
+///Line numbers start from 1.
+pub enum HexLinesCodecError {
+    ///Frame exceeds maximum length.
+    ///
+    ///When decoding, rest of the line is discarded.
+    MaxFrameLengthExceeded {
+        ///Line number
+        line: usize,
+    },
+    ///Line has odd number of characters
+    OddLength {
+        ///Line number
+        line: usize,
+    },
+    ///Line contains invalid character
+    InvalidChar {
+        ///Line number
+        line: usize,
+        ///Underlying error
+        error: DecodeError,
+    },
+    ///IO error
+    Io(io::Error),
+}
+
+impl fmt::Display for HexLinesCodecError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MaxFrameLengthExceeded { line } => fmt.write_fmt(format_args!("Line {}: max frame length exceeded", line)),
+            Self::OddLength { line } => fmt.write_fmt(format_args!("Line {}: odd number of hex characters", line)),
+            Self::InvalidChar { line, error } => fmt.write_fmt(format_args!("Line {}: {}", line, error)),
+            Self::Io(error) => fmt::Display::fmt(error, fmt),
+        }
+    }
+}
+
+impl std::error::Error for HexLinesCodecError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidChar { error, .. } => Some(error),
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for HexLinesCodecError {
+    #[inline(always)]
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[derive(Clone, Debug)]
+///Codec for newline delimited hex frames, each line being single binary frame.
+///
+///Decoding accepts both `\n` and `\r\n` line endings, while encoding always uses `\n`.
+pub struct HexLinesCodec {
+    table: CharTable,
+    max_length: usize,
+    //Offset of the buffer to continue newline search from
+    next_index: usize,
+    //Number of lines decoded so far
+    line: usize,
+    //Number of lines encoded so far
+    encoded_line: usize,
+    is_discarding: bool,
+}
+
+impl HexLinesCodec {
+    #[inline(always)]
+    ///Creates codec encoding with upper character set and without frame length limit
+    pub const fn upper() -> Self {
+        Self::with_table(CHAR_TABLE_UPPER)
+    }
+
+    #[inline(always)]
+    ///Creates codec encoding with lower character set and without frame length limit
+    pub const fn lower() -> Self {
+        Self::with_table(CHAR_TABLE_LOWER)
+    }
+
+    const fn with_table(table: CharTable) -> Self {
+        Self {
+            table,
+            max_length: usize::MAX,
+            next_index: 0,
+            line: 0,
+            encoded_line: 0,
+            is_discarding: false,
+        }
+    }
+
+    #[inline(always)]
+    ///Sets maximum length of decoded frame in bytes
+    pub const fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    #[inline(always)]
+    ///Returns maximum length of decoded frame in bytes
+    pub const fn get_max_length(&self) -> usize {
+        self.max_length
+    }
+
+    //Maximum length of line, excluding line ending
+    #[inline(always)]
+    const fn max_line_length(&self) -> usize {
+        required_encode_len(self.max_length)
+    }
+
+    fn decode_line(&self, mut line: &[u8]) -> Result<Bytes, HexLinesCodecError> {
+        if let Some((b'\r', rest)) = line.split_last() {
+            line = rest;
+        }
+
+        if line.len() > self.max_line_length() {
+            return Err(HexLinesCodecError::MaxFrameLengthExceeded { line: self.line });
+        } else if line.len() % 2 != 0 {
+            return Err(HexLinesCodecError::OddLength { line: self.line });
+        }
+
+        let mut frame = BytesMut::with_capacity(required_decode_len(line.len()));
+        match crate::unhex_from_buf(&mut line, &mut frame) {
+            Ok(_) => Ok(frame.freeze()),
+            Err(error) => Err(HexLinesCodecError::InvalidChar { line: self.line, error }),
+        }
+    }
+}
+
+impl Default for HexLinesCodec {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::lower()
+    }
+}
+
+impl Decoder for HexLinesCodec {
+    type Item = Bytes;
+    type Error = HexLinesCodecError;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        //Line may be followed by carriage return
+        let max_line_length = self.max_line_length().saturating_add(1);
+
+        loop {
+            let read_to = cmp::min(max_line_length.saturating_add(1), buf.len());
+            let newline = buf[self.next_index..read_to].iter().position(|byt| *byt == b'\n');
+
+            match (self.is_discarding, newline) {
+                (true, Some(offset)) => {
+                    buf.advance(self.next_index + offset + 1);
+                    self.next_index = 0;
+                    self.is_discarding = false;
+                },
+                (true, None) => {
+                    buf.advance(read_to);
+                    self.next_index = 0;
+                    if buf.is_empty() {
+                        return Ok(None);
+                    }
+                },
+                (false, Some(offset)) => {
+                    let newline_index = self.next_index + offset;
+                    self.next_index = 0;
+                    self.line = self.line.saturating_add(1);
+
+                    let line = buf.split_to(newline_index + 1);
+                    return self.decode_line(&line[..newline_index]).map(Some);
+                },
+                (false, None) if buf.len() > max_line_length => {
+                    self.line = self.line.saturating_add(1);
+                    self.is_discarding = true;
+                    return Err(HexLinesCodecError::MaxFrameLengthExceeded { line: self.line });
+                },
+                (false, None) => {
+                    self.next_index = read_to;
+                    return Ok(None);
+                },
+            }
+        }
+    }
+
+    fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(buf)? {
+            Some(frame) => Ok(Some(frame)),
+            None if buf.is_empty() || self.is_discarding => {
+                buf.clear();
+                self.next_index = 0;
+                Ok(None)
+            },
+            None => {
+                self.line = self.line.saturating_add(1);
+                self.next_index = 0;
+                let line = buf.split();
+                self.decode_line(&line).map(Some)
+            },
+        }
+    }
+}
+
+impl<T: AsRef<[u8]>> Encoder<T> for HexLinesCodec {
+    type Error = HexLinesCodecError;
+
+    fn encode(&mut self, frame: T, buf: &mut BytesMut) -> Result<(), Self::Error> {
+        let frame = frame.as_ref();
+        if frame.len() > self.max_length {
+            return Err(HexLinesCodecError::MaxFrameLengthExceeded { line: self.encoded_line.saturating_add(1) });
+        }
+
+        buf.reserve(required_encode_len(frame.len()).saturating_add(1));
+        crate::buf::hex_to_buf_mut(self.table, frame, buf);
+        buf.put_u8(b'\n');
+        self.encoded_line = self.encoded_line.saturating_add(1);
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+//!CSS hex colours: `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`.
+
+use core::{fmt, ops, str};
+
+use crate::const_fn::{self, UNHEX_INVALID_CHAR, UNHEX_TABLE};
+use crate::{unhex_pair, DecodeError, DecodeErrorAt, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+
+const PREFIX: u8 = b'#';
+const MAX_LEN: usize = 9;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Error parsing [Rgba](Rgba)
+pub enum ColorError {
+    ///Number of hex characters is neither 3, 4, 6 nor 8
+    InvalidLength(usize),
+    ///Invalid character encountered
+    InvalidChar(DecodeErrorAt),
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => fmt.write_fmt(format_args!("Invalid colour length {}", len)),
+            Self::InvalidChar(error) => fmt::Display::fmt(error, fmt),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ColorError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+///Options of colour formatting.
+///
+///By default `#rrggbb` is written in lower case, with alpha appended only if colour is not opaque.
+pub struct Format {
+    hash: bool,
+    upper: bool,
+    shorthand: bool,
+    alpha: bool,
+}
+
+impl Format {
+    #[inline(always)]
+    ///Creates default format
+    pub const fn new() -> Self {
+        Self {
+            hash: true,
+            upper: false,
+            shorthand: false,
+            alpha: false,
+        }
+    }
+
+    #[inline(always)]
+    ///Writes upper case characters
+    pub const fn upper(mut self) -> Self {
+        self.upper = true;
+        self
+    }
+
+    #[inline(always)]
+    ///Omits `#` prefix
+    pub const fn without_hash(mut self) -> Self {
+        self.hash = false;
+        self
+    }
+
+    #[inline(always)]
+    ///Writes `#rgb` or `#rgba`, if every channel consists of two equal nibbles
+    pub const fn shorthand(mut self) -> Self {
+        self.shorthand = true;
+        self
+    }
+
+    #[inline(always)]
+    ///Writes alpha even if colour is opaque
+    pub const fn always_alpha(mut self) -> Self {
+        self.alpha = true;
+        self
+    }
+}
+
+impl Default for Format {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Copy, Clone)]
+///Formatted colour, that can be used as `str`
+pub struct Buffer {
+    buffer: [u8; MAX_LEN],
+    len: u8,
+}
+
+impl Buffer {
+    #[inline(always)]
+    ///Returns formatted text
+    pub const fn as_str(&self) -> &str {
+        unsafe {
+            str::from_utf8_unchecked(self.buffer.split_at(self.len as usize).0)
+        }
+    }
+}
+
+impl ops::Deref for Buffer {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Buffer {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Buffer {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), fmt)
+    }
+}
+
+impl fmt::Debug for Buffer {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), fmt)
+    }
+}
+
+#[inline(always)]
+const fn invalid_char(text: &[u8], offset: usize) -> ColorError {
+    ColorError::InvalidChar(DecodeErrorAt {
+        offset,
+        error: DecodeError::InvalidChar(text[offset]),
+    })
+}
+
+#[inline(always)]
+const fn is_short(channel: u8) -> bool {
+    channel >> 4 == channel & 0xf
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+///RGBA colour
+pub struct Rgba {
+    ///Red channel
+    pub r: u8,
+    ///Green channel
+    pub g: u8,
+    ///Blue channel
+    pub b: u8,
+    ///Alpha channel, where `255` is opaque
+    pub a: u8,
+}
+
+impl Rgba {
+    #[inline(always)]
+    ///Creates new colour
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            a,
+        }
+    }
+
+    #[inline(always)]
+    ///Creates new opaque colour
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::new(r, g, b, u8::MAX)
+    }
+
+    ///Parses colour as `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, where `#` is optional.
+    ///
+    ///In shorthand forms each nibble is duplicated, i.e. `#f80` is the same as `#ff8800`.
+    pub const fn parse(text: &str) -> Result<Self, ColorError> {
+        let text = text.as_bytes();
+        let start = if !text.is_empty() && text[0] == PREFIX {
+            1
+        } else {
+            0
+        };
+
+        //Length of single channel in characters
+        let channel_len = match text.len() - start {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            len => return Err(ColorError::InvalidLength(len)),
+        };
+
+        let mut channels = [u8::MAX; 4];
+        let mut idx = 0;
+        let mut offset = start;
+        while offset < text.len() {
+            let pair = if channel_len == 1 {
+                [text[offset], text[offset]]
+            } else {
+                [text[offset], text[offset + 1]]
+            };
+            channels[idx] = match unhex_pair(pair) {
+                Ok(channel) => channel,
+                Err(_) => if UNHEX_TABLE[pair[0] as usize] == UNHEX_INVALID_CHAR {
+                    return Err(invalid_char(text, offset));
+                } else {
+                    return Err(invalid_char(text, offset + 1));
+                },
+            };
+            offset += channel_len;
+            idx += 1;
+        }
+
+        Ok(Self::new(channels[0], channels[1], channels[2], channels[3]))
+    }
+
+    ///Parses colour from literal, intended for use in const context.
+    ///
+    ///## Panics
+    ///
+    ///If `text` is not valid colour
+    pub const fn from_literal(text: &str) -> Self {
+        match Self::parse(text) {
+            Ok(color) => color,
+            Err(_) => panic!("Invalid colour literal"),
+        }
+    }
+
+    ///Formats colour according to `format`
+    pub const fn format(&self, format: Format) -> Buffer {
+        let table = if format.upper {
+            CHAR_TABLE_UPPER
+        } else {
+            CHAR_TABLE_LOWER
+        };
+        let channels = [self.r, self.g, self.b, self.a];
+        let channels_len = if format.alpha || self.a != u8::MAX {
+            4
+        } else {
+            3
+        };
+        let shorthand = format.shorthand && is_short(self.r) && is_short(self.g) && is_short(self.b) && is_short(self.a);
+
+        let mut buffer = [0u8; MAX_LEN];
+        let mut len = 0;
+        if format.hash {
+            buffer[len] = PREFIX;
+            len += 1;
+        }
+
+        let mut idx = 0;
+        while idx < channels_len {
+            let pair = const_fn::dec2hex(table, channels[idx]);
+            buffer[len] = pair.0[0];
+            len += 1;
+            if !shorthand {
+                buffer[len] = pair.0[1];
+                len += 1;
+            }
+            idx += 1;
+        }
+
+        Buffer {
+            buffer,
+            len: len as u8,
+        }
+    }
+}
+
+impl str::FromStr for Rgba {
+    type Err = ColorError;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl fmt::Display for Rgba {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.format(Format::new()), fmt)
+    }
+}
@@ -0,0 +1,81 @@
+use core::cmp::Ordering;
+use core::ptr;
+
+use crate::{unhex_nibble, DecodeErrorAt, Nibbles};
+
+///Compares `bytes` with value of hex string `hex`, without decoding it into temporary buffer.
+///
+///Comparison goes nibble by nibble, accepting hex characters in either case, hence odd length `hex`
+///is ordered as its nibbles, while for even length it is the same as comparing decoded bytes.
+///
+///Characters are validated only up to the first difference.
+pub fn cmp_hex(bytes: &[u8], hex: &[u8]) -> Result<Ordering, DecodeErrorAt> {
+    for (offset, (nibble, ch)) in Nibbles::new(bytes).zip(hex.iter()).enumerate() {
+        match unhex_nibble(*ch) {
+            Ok(value) => match nibble.cmp(&value) {
+                Ordering::Equal => (),
+                ordering => return Ok(ordering),
+            },
+            Err(error) => return Err(DecodeErrorAt {
+                offset,
+                error,
+            }),
+        }
+    }
+
+    Ok(bytes.len().saturating_mul(2).cmp(&hex.len()))
+}
+
+#[inline]
+///Returns whether `hex` encodes exactly `bytes`, accepting hex characters in either case.
+///
+///Invalid characters are never equal to anything.
+///
+///Comparison stops at the first difference, use [ct_eq_hex](ct_eq_hex) to compare secrets.
+pub fn eq_hex(bytes: &[u8], hex: &[u8]) -> bool {
+    hex.len() == bytes.len().saturating_mul(2) && cmp_hex(bytes, hex) == Ok(Ordering::Equal)
+}
+
+#[inline(always)]
+//Decodes character without branches or table lookups, returning nibble value and `0xff` if valid, `0` otherwise
+fn ct_nibble(ch: u8) -> (u8, u8) {
+    let ch = ch as i16;
+    //Folds letters into lower case, while digits are unaffected
+    let folded = ch | 0x20;
+    //Arithmetic shift gives all ones if both ends are negative, i.e. character is within range
+    let digit = ((0x2f - ch) & (ch - 0x3a)) >> 8;
+    let letter = ((0x60 - folded) & (folded - 0x67)) >> 8;
+    let value = (digit & (ch - 0x30)) | (letter & (folded - 0x57));
+    (value as u8, (digit | letter) as u8)
+}
+
+///Constant time version of [eq_hex](eq_hex), intended to verify MAC or token against its hex text.
+///
+///Time depends only on length of inputs: all characters are processed without short-circuit on mismatch,
+///invalid characters are detected without branches or table lookups and result is reduced without branches.
+///Accumulators are read via volatile loads on every iteration to prevent compiler from introducing early exit.
+///
+///Lengths are not considered secret, hence mismatch of lengths returns `false` immediately.
+pub fn ct_eq_hex(bytes: &[u8], hex: &[u8]) -> bool {
+    if hex.len() != bytes.len().saturating_mul(2) {
+        return false;
+    }
+
+    let mut diff = 0u8;
+    let mut valid = 0xffu8;
+    for (byte, pair) in bytes.iter().zip(hex.chunks_exact(2)) {
+        let (high, high_valid) = ct_nibble(pair[0]);
+        let (low, low_valid) = ct_nibble(pair[1]);
+        diff |= ((high << 4) | low) ^ byte;
+        valid &= high_valid & low_valid;
+        //Volatile reads hide accumulated values on every iteration, so that compiler cannot exit loop early once they are settled
+        diff = unsafe { ptr::read_volatile(&diff) };
+        valid = unsafe { ptr::read_volatile(&valid) };
+    }
+
+    //Reduces to single bit arithmetically, as comparison with zero could be compiled into branch
+    let mismatch = (diff | !valid) as u16;
+    let is_equal = (mismatch.wrapping_sub(1) >> 8) as u8 & 1;
+    let is_equal = unsafe { ptr::read_volatile(&is_equal) };
+    is_equal != 0
+}
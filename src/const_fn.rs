@@ -3,7 +3,7 @@ use core::mem;
 use crate::{CharPair, CharTable, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER, DecodeError};
 
 #[inline(always)]
-pub(crate) const fn dec2hex(table: CharTable, byt: u8) -> CharPair {
+pub(crate) const fn dec2hex(table: &[u8; 16], byt: u8) -> CharPair {
     let buf = [
         table[(byt.wrapping_shr(4) & 0xf) as usize],
         table[(byt & 0xf) as usize],
@@ -48,8 +48,8 @@ const fn hex2dec(ch: u8) -> Result<u8, DecodeError> {
     }
 }
 
-const UNHEX_INVALID_CHAR: u8 = 0xff;
-const UNHEX_TABLE: &[u8; 256] = &{
+pub(crate) const UNHEX_INVALID_CHAR: u8 = 0xff;
+pub(crate) const UNHEX_TABLE: &[u8; 256] = &{
     let mut res = [0u8; 256];
     let mut idx = 0usize;
     while idx <= (u8::MAX as usize) {
@@ -62,11 +62,25 @@ const UNHEX_TABLE: &[u8; 256] = &{
     res
 };
 
+#[inline(always)]
+///Converts single hex character into its nibble value
+pub const fn unhex_nibble(ch: u8) -> Result<u8, DecodeError> {
+    match UNHEX_TABLE[ch as usize] {
+        UNHEX_INVALID_CHAR => Err(DecodeError::InvalidChar(ch)),
+        nibble => Ok(nibble),
+    }
+}
+
 #[inline(always)]
 ///Converts hex character pair into underlying byte
 pub const fn unhex_pair(ch: [u8; 2]) -> Result<u8, DecodeError> {
+    unhex_pair_with(UNHEX_TABLE, ch)
+}
+
+#[inline(always)]
+pub(crate) const fn unhex_pair_with(table: &[u8; 256], ch: [u8; 2]) -> Result<u8, DecodeError> {
     let (left, right) = unsafe {
-        let table = UNHEX_TABLE.as_ptr();
+        let table = table.as_ptr();
         //This is always valid because u8::MAX value will always fit table
         (*table.add(ch[0] as usize), *table.add(ch[1] as usize))
     };
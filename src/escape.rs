@@ -0,0 +1,276 @@
+//!Escaped byte string encoding, rendering printable ASCII as is and everything else as `\xNN`.
+//!
+//!Output is compatible with C, Rust and Python string literals, depending on chosen [Style](Style).
+
+use core::{fmt, mem, slice, str};
+
+use crate::{const_fn, unhex_pair, CHAR_TABLE_LOWER};
+
+//Number of characters encoded per single write_str call
+const BUFFER_LEN: usize = 256;
+const MAX_ESCAPE_LEN: usize = 4;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+///Syntax of escaped string
+pub enum Style {
+    ///C string literal.
+    ///
+    ///As `\x` escape consumes any number of hex digits in C, hex digit following `\xNN` is escaped too.
+    ///
+    ///Decoding supports octal escapes and `\a`, `\b`, `\f`, `\v`, `\?` shorthands.
+    C,
+    ///Rust byte string literal, i.e. `b"..."`
+    ///
+    ///Decoding supports `\0` and line continuation.
+    Rust,
+    ///Python bytes literal, i.e. `b"..."`
+    ///
+    ///Decoding supports octal escapes, `\a`, `\b`, `\f`, `\v` shorthands and line continuation.
+    Python,
+}
+
+impl Style {
+    #[inline(always)]
+    const fn literal_prefix(self) -> &'static str {
+        match self {
+            Self::C => "\"",
+            Self::Rust | Self::Python => "b\"",
+        }
+    }
+}
+
+///Length sufficient to escape input of `len` bytes
+pub const fn required_encode_len(len: usize) -> usize {
+    len.saturating_mul(MAX_ESCAPE_LEN)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Error indicating invalid escape sequence
+pub struct InvalidEscape {
+    ///Offset of `\` within input
+    pub offset: usize,
+}
+
+impl fmt::Display for InvalidEscape {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("Invalid escape sequence at offset {}", self.offset))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidEscape {}
+
+//Returns escaped representation of byte and whether it is `\x` escape
+#[inline]
+fn escape_byte(style: Style, byt: u8, after_hex: bool) -> ([u8; MAX_ESCAPE_LEN], usize, bool) {
+    let shorthand = match byt {
+        b'\n' => b'n',
+        b'\t' => b't',
+        b'\r' => b'r',
+        b'\\' => b'\\',
+        b'"' => b'"',
+        0 if style == Style::Rust => b'0',
+        byt if (b' '..=b'~').contains(&byt) && !(after_hex && style == Style::C && byt.is_ascii_hexdigit()) => {
+            return ([byt, 0, 0, 0], 1, false);
+        },
+        byt => {
+            let pair = const_fn::dec2hex(CHAR_TABLE_LOWER, byt);
+            return ([b'\\', b'x', pair.0[0], pair.0[1]], 4, true);
+        }
+    };
+
+    ([b'\\', shorthand, 0, 0], 2, false)
+}
+
+//Returns number of consumed input bytes and number of written characters
+fn encode_with(style: Style, after_hex: &mut bool, input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> (usize, usize) {
+    let mut written = 0;
+
+    for (idx, byt) in input.iter().enumerate() {
+        let (escaped, len, is_hex) = escape_byte(style, *byt, *after_hex);
+        let out = match out.get_mut(written..written + len) {
+            Some(out) => out,
+            None => return (idx, written),
+        };
+
+        for (out, ch) in out.iter_mut().zip(escaped.iter()) {
+            *out = mem::MaybeUninit::new(*ch);
+        }
+        written += len;
+        *after_hex = is_hex;
+    }
+
+    (input.len(), written)
+}
+
+//Parses digits of numeric escape, returning its value and number of digits.
+fn parse_digits(input: &[u8], radix: u32, max_len: usize) -> Option<(u8, usize)> {
+    let mut value = 0u32;
+    let mut len = 0;
+
+    while len < max_len {
+        match input.get(len).and_then(|ch| (*ch as char).to_digit(radix)) {
+            Some(digit) => value = value.saturating_mul(radix).saturating_add(digit),
+            None => break,
+        }
+        len += 1;
+    }
+
+    if len == 0 || value > u8::MAX as u32 {
+        None
+    } else {
+        Some((value as u8, len))
+    }
+}
+
+//Parses escape sequence following `\`, returning its byte, if any, and number of consumed characters.
+fn parse_escape(style: Style, input: &[u8]) -> Option<(Option<u8>, usize)> {
+    let byt = match (input.first()?, style) {
+        (b'n', _) => b'\n',
+        (b't', _) => b'\t',
+        (b'r', _) => b'\r',
+        (b'\\', _) => b'\\',
+        (b'"', _) => b'"',
+        (b'\'', _) => b'\'',
+        (b'0', Style::Rust) => 0,
+        (b'a', Style::C | Style::Python) => 0x07,
+        (b'b', Style::C | Style::Python) => 0x08,
+        (b'f', Style::C | Style::Python) => 0x0c,
+        (b'v', Style::C | Style::Python) => 0x0b,
+        (b'?', Style::C) => b'?',
+        (b'x', Style::C) => {
+            let (byt, len) = parse_digits(&input[1..], 16, usize::MAX)?;
+            return Some((Some(byt), len + 1));
+        },
+        (b'x', Style::Rust | Style::Python) => {
+            let byt = match input.get(1..3) {
+                Some(pair) => unhex_pair([pair[0], pair[1]]).ok()?,
+                None => return None,
+            };
+            return Some((Some(byt), 3));
+        },
+        (b'0'..=b'7', Style::C | Style::Python) => {
+            let (byt, len) = parse_digits(input, 8, 3)?;
+            return Some((Some(byt), len));
+        },
+        (b'\n', Style::Python) => return Some((None, 1)),
+        (b'\n', Style::Rust) => {
+            let whitespace = input[1..].iter().take_while(|ch| ch.is_ascii_whitespace()).count();
+            return Some((None, whitespace + 1));
+        },
+        _ => return None,
+    };
+
+    Some((Some(byt), 1))
+}
+
+#[inline]
+///Escapes `input` into `out` according to `style`, without surrounding quotes.
+///
+///Output is truncated by its size, if necessary, without splitting escape sequences.
+///
+///Returns number of characters written.
+pub fn encode(input: &[u8], style: Style, out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    encode_with(style, &mut false, input, out).1
+}
+
+///Unescapes `input` written according to `style`, without surrounding quotes, into `out`.
+///
+///Characters outside of escape sequences are written as is.
+///
+///Output is truncated by its size, if necessary.
+pub fn decode(input: &[u8], style: Style, out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, InvalidEscape> {
+    let mut idx = 0;
+    let mut written = 0;
+
+    while idx < input.len() && written < out.len() {
+        let byt = if input[idx] == b'\\' {
+            match parse_escape(style, &input[idx + 1..]) {
+                Some((byt, len)) => {
+                    idx += len + 1;
+                    match byt {
+                        Some(byt) => byt,
+                        None => continue,
+                    }
+                },
+                None => return Err(InvalidEscape {
+                    offset: idx,
+                }),
+            }
+        } else {
+            idx += 1;
+            input[idx - 1]
+        };
+
+        out[written] = mem::MaybeUninit::new(byt);
+        written += 1;
+    }
+
+    Ok(written)
+}
+
+#[derive(Copy, Clone)]
+///Escaped string formatting adapter.
+///
+///`Display` renders escaped data without quotes, while `Debug` renders it as string literal of its [Style](Style).
+pub struct Encoder<'a> {
+    data: &'a [u8],
+    style: Style,
+}
+
+impl<'a> Encoder<'a> {
+    #[inline(always)]
+    ///Creates new instance
+    pub const fn new(data: &'a [u8], style: Style) -> Self {
+        Self {
+            data,
+            style,
+        }
+    }
+
+    #[inline(always)]
+    ///Creates new instance with C style
+    pub const fn c(data: &'a [u8]) -> Self {
+        Self::new(data, Style::C)
+    }
+
+    #[inline(always)]
+    ///Creates new instance with Rust style
+    pub const fn rust(data: &'a [u8]) -> Self {
+        Self::new(data, Style::Rust)
+    }
+
+    #[inline(always)]
+    ///Creates new instance with Python style
+    pub const fn python(data: &'a [u8]) -> Self {
+        Self::new(data, Style::Python)
+    }
+}
+
+impl fmt::Display for Encoder<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [mem::MaybeUninit::<u8>::uninit(); BUFFER_LEN];
+        let mut after_hex = false;
+        let mut data = self.data;
+
+        while !data.is_empty() {
+            let (consumed, len) = encode_with(self.style, &mut after_hex, data, &mut buffer);
+            data = &data[consumed..];
+            let chunk = unsafe {
+                str::from_utf8_unchecked(slice::from_raw_parts(buffer.as_ptr() as *const u8, len))
+            };
+            fmt.write_str(chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Encoder<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.style.literal_prefix())?;
+        fmt::Display::fmt(self, fmt)?;
+        fmt.write_str("\"")
+    }
+}
@@ -0,0 +1,255 @@
+//!MAC address (EUI-48) and EUI-64 identifiers in common text notations.
+
+use core::{fmt, str};
+
+use crate::const_fn::{self, UNHEX_INVALID_CHAR, UNHEX_TABLE};
+use crate::{unhex_pair, CharTable, DecodeError, DecodeErrorAt, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+///Text notation of identifier
+pub enum Notation {
+    ///`aa:bb:cc:dd:ee:ff`
+    Colon,
+    ///`aa-bb-cc-dd-ee-ff`
+    Hyphen,
+    ///Cisco notation `aabb.ccdd.eeff`, grouping two bytes together
+    Dot,
+    ///`aabbccddeeff`
+    Bare,
+}
+
+impl Notation {
+    #[inline(always)]
+    const fn separator(self) -> Option<u8> {
+        match self {
+            Self::Colon => Some(b':'),
+            Self::Hyphen => Some(b'-'),
+            Self::Dot => Some(b'.'),
+            Self::Bare => None,
+        }
+    }
+
+    #[inline(always)]
+    //Number of bytes between separators
+    const fn group_len(self) -> usize {
+        match self {
+            Self::Dot => 2,
+            Self::Colon | Self::Hyphen | Self::Bare => 1,
+        }
+    }
+
+    ///Returns length of text for identifier of `len` bytes
+    pub const fn text_len(self, len: usize) -> usize {
+        let hex_len = len.saturating_mul(2);
+        match self.separator() {
+            Some(_) if len > 0 => hex_len + len.div_ceil(self.group_len()) - 1,
+            _ => hex_len,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Error parsing [Eui](Eui)
+pub enum EuiError {
+    ///Length doesn't match any notation
+    InvalidLength(usize),
+    ///Separator differs from the first one, with offset of separator
+    MixedSeparators(usize),
+    ///Character is not separator of notation, with offset where separator is expected
+    InvalidSeparator(usize),
+    ///Invalid character encountered
+    InvalidChar(DecodeErrorAt),
+}
+
+impl fmt::Display for EuiError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => fmt.write_fmt(format_args!("Invalid identifier length {}", len)),
+            Self::MixedSeparators(offset) => fmt.write_fmt(format_args!("Mixed separators at offset {}", offset)),
+            Self::InvalidSeparator(offset) => fmt.write_fmt(format_args!("Expected separator at offset {}", offset)),
+            Self::InvalidChar(error) => fmt::Display::fmt(error, fmt),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EuiError {}
+
+#[inline(always)]
+const fn invalid_char(text: &[u8], offset: usize) -> EuiError {
+    EuiError::InvalidChar(DecodeErrorAt {
+        offset,
+        error: DecodeError::InvalidChar(text[offset]),
+    })
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+///Extended unique identifier of `N` bytes.
+///
+///Identifier can be created from literal at compile time:
+///
+///```rust
+///use based16::eui::Eui48;
+///
+///const MAC: Eui48 = Eui48::from_literal("00:1a:2b:3c:4d:5e");
+///assert_eq!(MAC.0, [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]);
+///```
+pub struct Eui<const N: usize>(pub [u8; N]);
+
+///MAC address
+pub type Eui48 = Eui<6>;
+///EUI-64 identifier
+pub type Eui64 = Eui<8>;
+
+impl<const N: usize> Eui<N> {
+    #[inline(always)]
+    ///Creates new instance from bytes
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    #[inline(always)]
+    ///Returns underlying bytes
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    ///Parses identifier in any of [Notation](Notation), detecting it by length and first separator.
+    pub const fn parse(text: &str) -> Result<Self, EuiError> {
+        let text = text.as_bytes();
+
+        let notation = if text.len() == Notation::Bare.text_len(N) {
+            Notation::Bare
+        } else if text.len() == Notation::Colon.text_len(N) && text[2] == b':' {
+            Notation::Colon
+        } else if text.len() == Notation::Hyphen.text_len(N) && text[2] == b'-' {
+            Notation::Hyphen
+        } else if N % 2 == 0 && text.len() == Notation::Dot.text_len(N) {
+            Notation::Dot
+        } else if text.len() == Notation::Colon.text_len(N) {
+            return Err(EuiError::InvalidSeparator(2));
+        } else {
+            return Err(EuiError::InvalidLength(text.len()));
+        };
+
+        let mut bytes = [0u8; N];
+        let mut offset = 0;
+        let mut idx = 0;
+        while idx < N {
+            if idx > 0 && idx % notation.group_len() == 0 {
+                if let Some(separator) = notation.separator() {
+                    let ch = text[offset];
+                    if ch == separator {
+                        offset += 1;
+                    } else if ch == b':' || ch == b'-' || ch == b'.' {
+                        return Err(EuiError::MixedSeparators(offset));
+                    } else {
+                        return Err(EuiError::InvalidSeparator(offset));
+                    }
+                }
+            }
+
+            bytes[idx] = match unhex_pair([text[offset], text[offset + 1]]) {
+                Ok(byte) => byte,
+                Err(_) => if UNHEX_TABLE[text[offset] as usize] == UNHEX_INVALID_CHAR {
+                    return Err(invalid_char(text, offset));
+                } else {
+                    return Err(invalid_char(text, offset + 1));
+                },
+            };
+            offset += 2;
+            idx += 1;
+        }
+
+        Ok(Self(bytes))
+    }
+
+    ///Parses identifier from literal, intended for use in const context.
+    ///
+    ///## Panics
+    ///
+    ///If `text` is not valid identifier
+    pub const fn from_literal(text: &str) -> Self {
+        match Self::parse(text) {
+            Ok(eui) => eui,
+            Err(_) => panic!("Invalid EUI literal"),
+        }
+    }
+
+    #[inline(always)]
+    ///Creates lower case formatting adapter with specified `notation`
+    pub const fn lower(&self, notation: Notation) -> Encoder<'_, N> {
+        Encoder {
+            table: CHAR_TABLE_LOWER,
+            data: &self.0,
+            notation,
+        }
+    }
+
+    #[inline(always)]
+    ///Creates upper case formatting adapter with specified `notation`
+    pub const fn upper(&self, notation: Notation) -> Encoder<'_, N> {
+        Encoder {
+            table: CHAR_TABLE_UPPER,
+            data: &self.0,
+            notation,
+        }
+    }
+}
+
+impl<const N: usize> str::FromStr for Eui<N> {
+    type Err = EuiError;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Eui<N> {
+    #[inline(always)]
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<const N: usize> fmt::Display for Eui<N> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.lower(Notation::Colon), fmt)
+    }
+}
+
+impl<const N: usize> fmt::Debug for Eui<N> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("Eui(")?;
+        fmt::Display::fmt(self, fmt)?;
+        fmt.write_str(")")
+    }
+}
+
+#[derive(Copy, Clone)]
+///Formatting adapter of [Eui](Eui), rendering it in specified [Notation](Notation)
+pub struct Encoder<'a, const N: usize> {
+    table: CharTable,
+    data: &'a [u8; N],
+    notation: Notation,
+}
+
+impl<const N: usize> fmt::Display for Encoder<'_, N> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        for (idx, byte) in self.data.iter().enumerate() {
+            if idx > 0 && idx % self.notation.group_len() == 0 {
+                if let Some(separator) = self.notation.separator() {
+                    fmt.write_char(separator as char)?;
+                }
+            }
+            fmt.write_str(&const_fn::dec2hex(self.table, *byte))?;
+        }
+
+        Ok(())
+    }
+}
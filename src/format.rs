@@ -0,0 +1,157 @@
+use core::{fmt, mem, slice, str};
+
+use crate::{arch, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+
+//Number of characters encoded per single write_str call
+const BUFFER_LEN: usize = 256;
+const PREFIX: &str = "0x";
+const ELLIPSIS: &str = "...";
+
+pub(crate) fn encode_to_fmt<W: fmt::Write + ?Sized>(table: &[u8; 16], input: &[u8], out: &mut W) -> fmt::Result {
+    let mut buffer = [mem::MaybeUninit::<u8>::uninit(); BUFFER_LEN];
+
+    for chunk in input.chunks(BUFFER_LEN / 2) {
+        let len = arch::hex(table, chunk, &mut buffer);
+        let chunk = unsafe {
+            str::from_utf8_unchecked(slice::from_raw_parts(buffer.as_ptr() as *const u8, len))
+        };
+        out.write_str(chunk)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn encode_rev_to_fmt<W: fmt::Write + ?Sized>(table: &[u8; 16], input: &[u8], out: &mut W) -> fmt::Result {
+    let mut buffer = [mem::MaybeUninit::<u8>::uninit(); BUFFER_LEN];
+
+    for chunk in input.rchunks(BUFFER_LEN / 2) {
+        let len = arch::hex_rev(table, chunk, &mut buffer);
+        let chunk = unsafe {
+            str::from_utf8_unchecked(slice::from_raw_parts(buffer.as_ptr() as *const u8, len))
+        };
+        out.write_str(chunk)?;
+    }
+
+    Ok(())
+}
+
+#[inline(always)]
+///Writes upper case hex into `out`, issuing single `write_str` per block of input.
+pub fn hex_upper_to_fmt<W: fmt::Write + ?Sized>(input: &[u8], out: &mut W) -> fmt::Result {
+    encode_to_fmt(CHAR_TABLE_UPPER, input, out)
+}
+
+#[inline(always)]
+///Writes lower case hex into `out`, issuing single `write_str` per block of input.
+pub fn hex_lower_to_fmt<W: fmt::Write + ?Sized>(input: &[u8], out: &mut W) -> fmt::Result {
+    encode_to_fmt(CHAR_TABLE_LOWER, input, out)
+}
+
+fn write_fill(fmt: &mut fmt::Formatter<'_>, len: usize) -> fmt::Result {
+    use fmt::Write;
+
+    let fill = fmt.fill();
+    for _ in 0..len {
+        fmt.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+///Writes `len` characters produced by `write`, honouring formatter flags:
+///
+///- `prefix` adds `0x`, which caller sets from `#` flag, except for `Debug` where `#` only requests pretty printing;
+///- `truncated` appends ellipsis, as caller limits data according to precision;
+///- width pads output with fill character according to alignment (left by default).
+///
+///Output, including prefix and ellipsis, is enclosed in `quote`.
+pub(crate) fn write_hex_with<F: FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result>(fmt: &mut fmt::Formatter<'_>, prefix: bool, quote: &str, len: usize, truncated: bool, write: F) -> fmt::Result {
+    let prefix = if prefix {
+        PREFIX
+    } else {
+        ""
+    };
+    let ellipsis = if truncated {
+        ELLIPSIS
+    } else {
+        ""
+    };
+
+    let len = quote.len()
+                   .saturating_mul(2)
+                   .saturating_add(prefix.len())
+                   .saturating_add(len)
+                   .saturating_add(ellipsis.len());
+    let padding = fmt.width().unwrap_or(0).saturating_sub(len);
+    let (pre_padding, post_padding) = match fmt.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+
+    write_fill(fmt, pre_padding)?;
+    fmt.write_str(quote)?;
+    fmt.write_str(prefix)?;
+    write(fmt)?;
+    fmt.write_str(ellipsis)?;
+    fmt.write_str(quote)?;
+    write_fill(fmt, post_padding)
+}
+
+///Formats `data` as hex, honouring formatter flags as [write_hex_with](write_hex_with), while precision limits number of bytes encoded.
+///
+///If `rev` is set, bytes are encoded in reverse order.
+pub(crate) fn fmt_hex(table: &[u8; 16], data: &[u8], rev: bool, prefix: bool, quote: &str, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let (data, truncated) = match fmt.precision() {
+        Some(precision) if precision < data.len() => if rev {
+            (&data[data.len() - precision..], true)
+        } else {
+            (&data[..precision], true)
+        },
+        _ => (data, false),
+    };
+
+    write_hex_with(fmt, prefix, quote, data.len().saturating_mul(2), truncated, |fmt| if rev {
+        encode_rev_to_fmt(table, data, fmt)
+    } else {
+        encode_to_fmt(table, data, fmt)
+    })
+}
+
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+///Formatting wrapper to render bytes as hex via `LowerHex` or `UpperHex`.
+///
+///`Display` and `Debug` use lower case.
+///
+///Supports the same flags as [Encoder](crate::Encoder): `#` for `0x` prefix, precision as maximum number of bytes and fill/alignment/width.
+///As with `Encoder`, `Debug` never adds prefix.
+pub struct Fmt<T>(pub T);
+
+impl<T: AsRef<[u8]>> fmt::LowerHex for Fmt<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(CHAR_TABLE_LOWER, self.0.as_ref(), false, fmt.alternate(), "", fmt)
+    }
+}
+
+impl<T: AsRef<[u8]>> fmt::UpperHex for Fmt<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(CHAR_TABLE_UPPER, self.0.as_ref(), false, fmt.alternate(), "", fmt)
+    }
+}
+
+impl<T: AsRef<[u8]>> fmt::Display for Fmt<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, fmt)
+    }
+}
+
+impl<T: AsRef<[u8]>> fmt::Debug for Fmt<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(CHAR_TABLE_LOWER, self.0.as_ref(), false, false, "\"", fmt)
+    }
+}
@@ -0,0 +1,270 @@
+use core::{cmp, mem};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::io;
+
+use crate::{arch, CharTable, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+use crate::required_decode_len;
+
+const BUFFER_LEN: usize = 4096;
+
+macro_rules! ready {
+    ($poll:expr) => {
+        match $poll {
+            Poll::Ready(result) => result,
+            Poll::Pending => return Poll::Pending,
+        }
+    };
+}
+
+///Writer adapter that hex encodes all data written into it before passing it to inner writer.
+///
+///Encoded data is buffered internally, hence it is necessary to flush or shutdown writer to make sure all data is written.
+///
+///Implements `AsyncWrite` for `tokio` and `futures-io` when corresponding features are enabled.
+pub struct HexEncodeWriter<W> {
+    inner: W,
+    table: CharTable,
+    buffer: [u8; BUFFER_LEN],
+    pos: usize,
+    len: usize,
+}
+
+impl<W> HexEncodeWriter<W> {
+    #[inline(always)]
+    ///Creates writer with upper character set
+    pub const fn upper(inner: W) -> Self {
+        Self::with_table(CHAR_TABLE_UPPER, inner)
+    }
+
+    #[inline(always)]
+    ///Creates writer with lower character set
+    pub const fn lower(inner: W) -> Self {
+        Self::with_table(CHAR_TABLE_LOWER, inner)
+    }
+
+    const fn with_table(table: CharTable, inner: W) -> Self {
+        Self {
+            inner,
+            table,
+            buffer: [0; BUFFER_LEN],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns reference to inner writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    #[inline(always)]
+    ///Returns mutable reference to inner writer
+    ///
+    ///Writing into it directly may corrupt encoded output if there is buffered data.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    #[inline(always)]
+    ///Returns inner writer, discarding buffered data.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    #[inline(always)]
+    ///Returns encoded data that is not yet written into inner writer.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer[self.pos..self.len]
+    }
+
+    fn poll_drain<F: FnMut(&mut W, &mut Context<'_>, &[u8]) -> Poll<io::Result<usize>>>(&mut self, cx: &mut Context<'_>, mut write: F) -> Poll<io::Result<()>> {
+        while self.pos < self.len {
+            match ready!(write(&mut self.inner, cx, &self.buffer[self.pos..self.len])) {
+                Ok(0) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                Ok(written) => self.pos = self.pos.saturating_add(written),
+                Err(error) => return Poll::Ready(Err(error)),
+            }
+        }
+
+        self.pos = 0;
+        self.len = 0;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_write_with<F: FnMut(&mut W, &mut Context<'_>, &[u8]) -> Poll<io::Result<usize>>>(&mut self, cx: &mut Context<'_>, input: &[u8], write: F) -> Poll<io::Result<usize>> {
+        if input.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        match self.poll_drain(cx, write) {
+            Poll::Ready(Ok(())) => (),
+            Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+            //Accept as much as we can buffer, waiting only when buffer is full
+            Poll::Pending => if self.buffer.len() - self.len + self.pos < 2 {
+                return Poll::Pending;
+            } else {
+                self.buffer.copy_within(self.pos..self.len, 0);
+                self.len -= self.pos;
+                self.pos = 0;
+            },
+        }
+
+        let output = &mut self.buffer[self.len..];
+        let output = unsafe {
+            &mut *(output as *mut [u8] as *mut [mem::MaybeUninit<u8>])
+        };
+        let written = arch::hex(self.table, input, output);
+        self.len = self.len.saturating_add(written);
+        Poll::Ready(Ok(written / 2))
+    }
+}
+
+///Reader adapter that decodes hex read from inner reader.
+///
+///Character pairs split across reads are carried over to the next read.
+///Reaching end of inner reader with odd number of characters results in `UnexpectedEof` error, while invalid characters result in `InvalidData` error.
+///
+///Implements `AsyncRead` for `tokio` and `futures-io` when corresponding features are enabled.
+pub struct HexDecodeReader<R> {
+    inner: R,
+    buffer: [u8; BUFFER_LEN],
+    pos: usize,
+    len: usize,
+    is_eof: bool,
+}
+
+impl<R> HexDecodeReader<R> {
+    #[inline(always)]
+    ///Creates new instance
+    pub const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buffer: [0; BUFFER_LEN],
+            pos: 0,
+            len: 0,
+            is_eof: false,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns reference to inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    #[inline(always)]
+    ///Returns mutable reference to inner reader
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    #[inline(always)]
+    ///Returns inner reader, discarding buffered data.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn poll_read_with<F: FnMut(&mut R, &mut Context<'_>, &mut [u8]) -> Poll<io::Result<usize>>>(&mut self, cx: &mut Context<'_>, output: &mut [mem::MaybeUninit<u8>], mut read: F) -> Poll<io::Result<usize>> {
+        if output.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        loop {
+            let available = self.len - self.pos;
+            if available >= 2 {
+                let input = &self.buffer[self.pos..self.len];
+                let len = cmp::min(required_decode_len(input.len()), output.len());
+                //Decoding is all or nothing, so on error keep input in case reader wants to inspect it
+                let written = arch::unhex(&input[..len * 2], output)?;
+                self.pos = self.pos.saturating_add(written * 2);
+                return Poll::Ready(Ok(written));
+            } else if self.is_eof {
+                return match available {
+                    0 => Poll::Ready(Ok(0)),
+                    _ => Poll::Ready(Err(io::Error::new(io::ErrorKind::UnexpectedEof, "odd number of hex characters"))),
+                };
+            }
+
+            //Carry over incomplete pair to the start of buffer
+            self.buffer.copy_within(self.pos..self.len, 0);
+            self.len = available;
+            self.pos = 0;
+
+            match ready!(read(&mut self.inner, cx, &mut self.buffer[self.len..])) {
+                Ok(0) => self.is_eof = true,
+                Ok(read) => self.len = self.len.saturating_add(read),
+                Err(error) => return Poll::Ready(Err(error)),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin> tokio::io::AsyncWrite for HexEncodeWriter<W> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        self.get_mut().poll_write_with(cx, buf, |inner, cx, buf| Pin::new(inner).poll_write(cx, buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx, |inner, cx, buf| Pin::new(inner).poll_write(cx, buf)))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx, |inner, cx, buf| Pin::new(inner).poll_write(cx, buf)))?;
+        Pin::new(&mut this.inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R: tokio::io::AsyncRead + Unpin> tokio::io::AsyncRead for HexDecodeReader<R> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut tokio::io::ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let output = unsafe {
+            buf.unfilled_mut()
+        };
+        let written = ready!(self.get_mut().poll_read_with(cx, output, |inner, cx, buf| {
+            let mut buf = tokio::io::ReadBuf::new(buf);
+            ready!(Pin::new(inner).poll_read(cx, &mut buf))?;
+            Poll::Ready(Ok(buf.filled().len()))
+        }))?;
+
+        unsafe {
+            buf.assume_init(written);
+        }
+        buf.advance(written);
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<W: futures_io::AsyncWrite + Unpin> futures_io::AsyncWrite for HexEncodeWriter<W> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        self.get_mut().poll_write_with(cx, buf, |inner, cx, buf| Pin::new(inner).poll_write(cx, buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx, |inner, cx, buf| Pin::new(inner).poll_write(cx, buf)))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx, |inner, cx, buf| Pin::new(inner).poll_write(cx, buf)))?;
+        Pin::new(&mut this.inner).poll_close(cx)
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<R: futures_io::AsyncRead + Unpin> futures_io::AsyncRead for HexDecodeReader<R> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let output = unsafe {
+            &mut *(buf as *mut [u8] as *mut [mem::MaybeUninit<u8>])
+        };
+        self.get_mut().poll_read_with(cx, output, |inner, cx, buf| Pin::new(inner).poll_read(cx, buf))
+    }
+}
@@ -2,25 +2,69 @@
 
 #![no_std]
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
+#![allow(clippy::style)]
+#![allow(clippy::manual_is_multiple_of)]
+
+#[cfg(feature = "std")]
+extern crate std;
 
 use core::{fmt, mem};
+use core::iter::FusedIterator;
 
 mod pair;
 pub use pair::CharPair;
 mod arch;
 mod const_fn;
 pub use const_fn::*;
+mod alphabet;
+pub use alphabet::{Alphabet, AlphabetError};
+pub mod modhex;
+pub mod percent;
+pub mod escape;
+pub mod quoted_printable;
+pub mod uuid;
+pub mod eui;
+pub mod color;
+pub mod pattern;
+mod format;
+pub use format::{Fmt, hex_upper_to_fmt, hex_lower_to_fmt};
+mod rev;
+pub use rev::{RevEncoder, RevDecoder};
+mod nibble;
+pub use nibble::Nibbles;
+mod validate;
+pub use validate::{Case, find_invalid, is_hex, validate, hex_case};
+pub use validate::{to_lower_in_place, to_upper_in_place, to_lower, to_upper};
+mod compare;
+pub use compare::{cmp_hex, eq_hex, ct_eq_hex};
+mod checked;
+pub use checked::{OutputTooSmall, TryDecodeError, checked_required_encode_len, try_hex_lower, try_hex_upper, try_unhex};
 #[cfg(feature = "alloc")]
 mod alloc;
 #[cfg(feature = "alloc")]
 pub use alloc::*;
+#[cfg(feature = "bytes")]
+mod buf;
+#[cfg(feature = "bytes")]
+pub use buf::*;
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+mod io;
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+pub use io::{HexEncodeWriter, HexDecodeReader};
+#[cfg(feature = "tokio-util")]
+mod codec;
+#[cfg(feature = "tokio-util")]
+pub use codec::{HexLinesCodec, HexLinesCodecError};
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "rayon")]
+pub use par::*;
 
 type CharTable = &'static [u8; 16];
 const CHAR_TABLE_LOWER: CharTable = b"0123456789abcdef";
 const CHAR_TABLE_UPPER: CharTable = b"0123456789ABCDEF";
 
-///Length required to HEX encode
+///Length required to HEX encode, saturating on overflow
 pub const fn required_encode_len(len: usize) -> usize {
     len.saturating_mul(2)
 }
@@ -30,25 +74,154 @@ pub const fn required_decode_len(len: usize) -> usize {
     len.saturating_div(2)
 }
 
+///Length required to decode HEX, including padded trailing character of odd length input
+pub const fn required_padded_decode_len(len: usize) -> usize {
+    len / 2 + len % 2
+}
+
 #[inline(always)]
-///Writes upper case hex into `out`
+///Writes upper case hex into `out`, truncating by its size, if necessary.
+///
+///Use [try_hex_upper](try_hex_upper) to fail instead.
 pub fn hex_upper(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
     arch::hex(CHAR_TABLE_UPPER, input, out)
 }
 
 #[inline(always)]
-///Writes lower case hex into `out`
+///Writes lower case hex into `out`, truncating by its size, if necessary.
+///
+///Use [try_hex_lower](try_hex_lower) to fail instead.
 pub fn hex_lower(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
     arch::hex(CHAR_TABLE_LOWER, input, out)
 }
 
 #[inline(always)]
 ///Decodes hex-encoded `input` into `out`, truncating by its size, if necessary.
+///
+///Trailing character of odd length input is ignored, use [try_unhex](try_unhex) to fail instead.
 pub fn unhex(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
     arch::unhex(input, out)
 }
 
-#[derive(Debug, Copy, Clone)]
+#[inline]
+///Writes hex into `out` using `alphabet`
+///
+///Alphabets other than contiguous ranges of digits and letters are encoded via SIMD only when `ssse3` target feature is enabled.
+pub fn hex_with_alphabet(alphabet: &Alphabet, input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    arch::hex(alphabet.symbols(), input, out)
+}
+
+#[inline]
+///Decodes `input` encoded with `alphabet` into `out`, truncating by its size, if necessary.
+///
+///Only [Alphabet::LOWER](Alphabet::LOWER) and [Alphabet::UPPER](Alphabet::UPPER) are decoded via SIMD, other alphabets use table lookup per character.
+pub fn unhex_with_alphabet(alphabet: &Alphabet, input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
+    if alphabet.is_hex() {
+        arch::unhex(input, out)
+    } else {
+        arch::unhex_with_table(alphabet.decode_table(), input, out)
+    }
+}
+
+#[inline(always)]
+///Writes upper case hex of `input` in reverse byte order into `out`
+///
+///Output is truncated by its size, starting from the last byte of `input`.
+pub fn hex_upper_rev(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    arch::hex_rev(CHAR_TABLE_UPPER, input, out)
+}
+
+#[inline(always)]
+///Writes lower case hex of `input` in reverse byte order into `out`
+///
+///Output is truncated by its size, starting from the last byte of `input`.
+pub fn hex_lower_rev(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    arch::hex_rev(CHAR_TABLE_LOWER, input, out)
+}
+
+#[inline(always)]
+///Decodes hex-encoded `input` into `out` in reverse byte order, truncating by its size, if necessary.
+///
+///Truncation drops bytes at the start of `input`, i.e. `out` always begins with the last encoded byte.
+pub fn unhex_rev(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
+    arch::unhex_rev(input, out)
+}
+
+#[inline]
+///Decodes hex-encoded `input` of any length into `out`, padding odd length input from the left.
+///
+///I.e. `abc` is decoded as `0a bc`. Output is truncated by its size, if necessary.
+pub fn unhex_pad_left(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
+    if input.len() % 2 == 0 {
+        return arch::unhex(input, out);
+    }
+
+    match out.split_first_mut() {
+        Some((first, out)) => {
+            *first = mem::MaybeUninit::new(unhex_nibble(input[0])?);
+            Ok(arch::unhex(&input[1..], out)? + 1)
+        },
+        None => Ok(0),
+    }
+}
+
+#[inline]
+///Decodes hex-encoded `input` of any length into `out`, padding odd length input from the right.
+///
+///I.e. `abc` is decoded as `ab c0`. Output is truncated by its size, if necessary.
+pub fn unhex_pad_right(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
+    let len = arch::unhex(input, out)?;
+    if input.len() % 2 == 0 || len < required_decode_len(input.len()) {
+        return Ok(len);
+    }
+
+    match out.get_mut(len) {
+        Some(last) => {
+            *last = mem::MaybeUninit::new(unhex_nibble(input[input.len() - 1])? << 4);
+            Ok(len + 1)
+        },
+        None => Ok(len),
+    }
+}
+
+#[inline(always)]
+///Decodes hex-encoded `buffer` into its own storage, returning decoded bytes at the front of `buffer`.
+///
+///Trailing character of odd length input is ignored.
+///
+///On error, content of `buffer` is unspecified as it may be partially overwritten.
+pub fn unhex_in_place(buffer: &mut [u8]) -> Result<&mut [u8], DecodeError> {
+    let len = arch::unhex_in_place(buffer)?;
+    Ok(&mut buffer[..len])
+}
+
+#[inline(always)]
+///Encodes first `len` bytes of `buffer` as upper case hex in place, expanding them to `len * 2` characters.
+///
+///## Panics
+///
+///If `buffer` is shorter than `len * 2`
+pub fn hex_upper_in_place(buffer: &mut [u8], len: usize) -> &mut str {
+    let len = arch::hex_in_place(CHAR_TABLE_UPPER, buffer, len);
+    unsafe {
+        core::str::from_utf8_unchecked_mut(&mut buffer[..len])
+    }
+}
+
+#[inline(always)]
+///Encodes first `len` bytes of `buffer` as lower case hex in place, expanding them to `len * 2` characters.
+///
+///## Panics
+///
+///If `buffer` is shorter than `len * 2`
+pub fn hex_lower_in_place(buffer: &mut [u8], len: usize) -> &mut str {
+    let len = arch::hex_in_place(CHAR_TABLE_LOWER, buffer, len);
+    unsafe {
+        core::str::from_utf8_unchecked_mut(&mut buffer[..len])
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 ///Error happening during decoding
 pub enum DecodeError {
     ///Invalid character encountered
@@ -63,11 +236,56 @@ impl DecodeError {
     }
 }
 
+impl fmt::Display for DecodeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChar(ch) => fmt.write_fmt(format_args!("Invalid character 0x{:02x}", ch)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+#[cfg(feature = "std")]
+impl From<DecodeError> for std::io::Error {
+    #[inline]
+    fn from(error: DecodeError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Decoding error with offset of invalid character within input
+pub struct DecodeErrorAt {
+    ///Offset of invalid character
+    pub offset: usize,
+    ///Underlying error
+    pub error: DecodeError,
+}
+
+impl fmt::Display for DecodeErrorAt {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("{} at offset {}", self.error, self.offset))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeErrorAt {}
+
 ///Hex encoder, implements iterator returning individual byte as pair of characters.
 ///
-///`Display` implementation renders current data without advancing iterator
+///`Display` implementation renders current data without advancing iterator.
+///
+///Formatting supports following flags:
+///
+///- `{:#}` prefixes output with `0x`;
+///- `{:.N}` limits output to first `N` bytes, appending `...` if data is truncated;
+///- `{:>W}` and other width/alignment options pad output with fill character.
+///
+///`Debug` quotes output and supports the same flags, except `{:#?}` which never adds prefix.
 pub struct Encoder<'a> {
-    table: CharTable,
+    table: &'a [u8; 16],
     data: &'a [u8],
 }
 
@@ -90,6 +308,15 @@ impl<'a> Encoder<'a> {
         }
     }
 
+    #[inline(always)]
+    ///Creates encoder with character set of `alphabet`
+    pub const fn with_alphabet(data: &'a [u8], alphabet: &'a Alphabet) -> Self {
+        Self {
+            table: alphabet.symbols(),
+            data,
+        }
+    }
+
     #[inline(always)]
     ///Get next byte encoded
     pub fn next_byte(&mut self) -> Option<CharPair> {
@@ -101,6 +328,46 @@ impl<'a> Encoder<'a> {
             None => None
         }
     }
+
+    #[inline(always)]
+    ///Get last byte encoded
+    pub fn next_byte_back(&mut self) -> Option<CharPair> {
+        match self.data.split_last() {
+            Some((byt, rest)) => {
+                self.data = rest;
+                Some(dec2hex(self.table, *byt))
+            },
+            None => None
+        }
+    }
+
+    #[inline(always)]
+    ///Returns remaining data, that is not encoded yet
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.data
+    }
+
+    #[inline]
+    ///Splits encoder into two at `mid` byte, preserving character set.
+    ///
+    ///First encoder contains bytes `[0, mid)` while second `[mid, len)`
+    ///
+    ///## Panics
+    ///
+    ///If `mid > len`
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        let (left, right) = self.data.split_at(mid);
+        (
+            Self {
+                table: self.table,
+                data: left,
+            },
+            Self {
+                table: self.table,
+                data: right,
+            }
+        )
+    }
 }
 
 impl Iterator for Encoder<'_> {
@@ -115,8 +382,39 @@ impl Iterator for Encoder<'_> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.data.len(), Some(self.data.len()))
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.data = self.data.get(n..).unwrap_or(&[]);
+        self.next_byte()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.data.len()
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_byte_back()
+    }
+}
+
+impl DoubleEndedIterator for Encoder<'_> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_byte_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.data = &self.data[..self.data.len().saturating_sub(n)];
+        self.next_byte_back()
+    }
 }
 
+impl FusedIterator for Encoder<'_> {}
+
 impl ExactSizeIterator for Encoder<'_> {
     #[inline(always)]
     fn len(&self) -> usize {
@@ -127,28 +425,22 @@ impl ExactSizeIterator for Encoder<'_> {
 impl fmt::Display for Encoder<'_> {
     #[inline(always)]
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for byt in self.data {
-            fmt.write_str(dec2hex(self.table, *byt).as_str())?;
-        }
-
-        Ok(())
+        format::fmt_hex(self.table, self.data, false, fmt.alternate(), "", fmt)
     }
 }
 
 impl fmt::Debug for Encoder<'_> {
     #[inline(always)]
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.write_str("\"")?;
-        for byt in self.data {
-            fmt.write_str(dec2hex(self.table, *byt).as_str())?;
-        }
-
-        fmt.write_str("\"")
+        format::fmt_hex(self.table, self.data, false, false, "\"", fmt)
     }
 }
 
 ///Decoder that transforms pairs of characters into individual decimal bytes
-pub struct Decoder<'a>(&'a [u8]);
+pub struct Decoder<'a> {
+    table: &'a [u8; 256],
+    data: &'a [u8],
+}
 
 impl<'a> Decoder<'a> {
     #[inline(always)]
@@ -157,28 +449,93 @@ impl<'a> Decoder<'a> {
         if data.len() % 2 != 0 {
             None
         } else {
-            Some(Self(data.as_bytes()))
+            Some(Self {
+                table: const_fn::UNHEX_TABLE,
+                data: data.as_bytes(),
+            })
+        }
+    }
+
+    #[inline(always)]
+    ///Creates new instance decoding characters of `alphabet`, validating that input has even length.
+    pub const fn with_alphabet(data: &'a str, alphabet: &'a Alphabet) -> Option<Self> {
+        if data.len() % 2 != 0 {
+            None
+        } else {
+            Some(Self {
+                table: alphabet.decode_table(),
+                data: data.as_bytes(),
+            })
         }
     }
 
     #[inline]
     fn inner_next_byte(&mut self) -> Result<u8, DecodeError> {
         let chunk = unsafe {
-            *(self.0.as_ptr() as *const [u8; 2])
+            *(self.data.as_ptr() as *const [u8; 2])
         };
-        self.0 = &self.0[2..];
-        unhex_pair(chunk)
+        self.data = &self.data[2..];
+        const_fn::unhex_pair_with(self.table, chunk)
+    }
+
+    #[inline]
+    fn inner_next_byte_back(&mut self) -> Result<u8, DecodeError> {
+        let idx = self.data.len() - 2;
+        let chunk = unsafe {
+            *(self.data.as_ptr().add(idx) as *const [u8; 2])
+        };
+        self.data = &self.data[..idx];
+        const_fn::unhex_pair_with(self.table, chunk)
     }
 
     #[inline]
     ///Gets next byte, returning error in case of invalid character
     pub fn next_byte(&mut self) -> Option<Result<u8, DecodeError>> {
-        if self.0.is_empty() {
+        if self.data.is_empty() {
             return None;
         }
 
         Some(self.inner_next_byte())
     }
+
+    #[inline]
+    ///Gets last byte, returning error in case of invalid character
+    pub fn next_byte_back(&mut self) -> Option<Result<u8, DecodeError>> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        Some(self.inner_next_byte_back())
+    }
+
+    #[inline(always)]
+    ///Returns remaining hex input, that is not decoded yet
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.data
+    }
+
+    #[inline]
+    ///Splits decoder into two at `mid` byte (i.e. `mid * 2` characters of input).
+    ///
+    ///First decoder contains bytes `[0, mid)` while second `[mid, len)`
+    ///
+    ///## Panics
+    ///
+    ///If `mid > len`
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        assert!(mid <= self.len(), "mid > len");
+        let (left, right) = self.data.split_at(required_encode_len(mid));
+        (
+            Self {
+                table: self.table,
+                data: left,
+            },
+            Self {
+                table: self.table,
+                data: right,
+            }
+        )
+    }
 }
 
 impl Iterator for Decoder<'_> {
@@ -191,13 +548,45 @@ impl Iterator for Decoder<'_> {
 
     #[inline(always)]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.0.len(), Some(self.0.len()))
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.data = self.data.get(required_encode_len(n)..).unwrap_or(&[]);
+        self.next_byte()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_byte_back()
+    }
+}
+
+impl DoubleEndedIterator for Decoder<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_byte_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.data = &self.data[..self.data.len().saturating_sub(required_encode_len(n))];
+        self.next_byte_back()
     }
 }
 
 impl ExactSizeIterator for Decoder<'_> {
     #[inline(always)]
     fn len(&self) -> usize {
-        self.0.len()
+        required_decode_len(self.data.len())
     }
 }
+
+impl FusedIterator for Decoder<'_> {}
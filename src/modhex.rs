@@ -0,0 +1,157 @@
+//!YubiKey Modhex encoding, using `cbdefghijklnrtuv` instead of hex digits.
+//!
+//!API mirrors hex one, with additional helpers to work with YubiKey OTPs.
+
+use core::{cmp, fmt, mem};
+
+use crate::{Alphabet, DecodeError, Decoder, Encoder, CHAR_TABLE_LOWER};
+
+///Modhex alphabet
+pub const ALPHABET: Alphabet = match Alphabet::new(b"cbdefghijklnrtuv") {
+    Ok(alphabet) => alphabet,
+    Err(_) => panic!("Invalid modhex alphabet"),
+};
+
+#[inline(always)]
+///Writes modhex into `out`
+pub fn encode(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    crate::hex_with_alphabet(&ALPHABET, input, out)
+}
+
+#[inline(always)]
+///Decodes modhex-encoded `input` into `out`, truncating by its size, if necessary.
+pub fn decode(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
+    crate::unhex_with_alphabet(&ALPHABET, input, out)
+}
+
+#[inline(always)]
+///Creates modhex [Encoder](crate::Encoder)
+pub const fn encoder(data: &[u8]) -> Encoder<'_> {
+    Encoder::with_alphabet(data, &ALPHABET)
+}
+
+#[inline(always)]
+///Creates modhex [Decoder](crate::Decoder), validating that input has even length.
+pub const fn decoder(data: &str) -> Option<Decoder<'_>> {
+    Decoder::with_alphabet(data, &ALPHABET)
+}
+
+///Converts modhex characters into lower case hex characters, without decoding into bytes.
+///
+///Input of any length is accepted, and output is truncated by `out` size, if necessary.
+pub fn to_hex(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
+    let len = cmp::min(input.len(), out.len());
+
+    for (ch, out) in input[..len].iter().zip(out.iter_mut()) {
+        match ALPHABET.nibble(*ch) {
+            Some(nibble) => *out = mem::MaybeUninit::new(CHAR_TABLE_LOWER[nibble as usize]),
+            None => return Err(DecodeError::unexpected_char(*ch)),
+        }
+    }
+
+    Ok(len)
+}
+
+///Converts hex characters of either case into modhex characters, without decoding into bytes.
+///
+///Input of any length is accepted, and output is truncated by `out` size, if necessary.
+pub fn from_hex(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeError> {
+    let len = cmp::min(input.len(), out.len());
+
+    for (ch, out) in input[..len].iter().zip(out.iter_mut()) {
+        match Alphabet::LOWER.nibble(*ch) {
+            Some(nibble) => *out = mem::MaybeUninit::new(ALPHABET.symbols()[nibble as usize]),
+            None => return Err(DecodeError::unexpected_char(*ch)),
+        }
+    }
+
+    Ok(len)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Error parsing [Otp](Otp)
+pub enum OtpError {
+    ///OTP length is invalid.
+    ///
+    ///It must be even, and between [Otp::MIN_LEN](Otp::MIN_LEN) and [Otp::MAX_LEN](Otp::MAX_LEN)
+    InvalidLength(usize),
+    ///OTP contains non-modhex character
+    InvalidChar(DecodeError),
+}
+
+impl fmt::Display for OtpError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => fmt.write_fmt(format_args!("Invalid OTP length {}", len)),
+            Self::InvalidChar(error) => fmt::Display::fmt(error, fmt),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OtpError {}
+
+const TOKEN_LEN: usize = 32;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///YubiKey OTP, consisting of public ID followed by 32 characters of encrypted token.
+pub struct Otp<'a> {
+    public_id: &'a str,
+    token: &'a str,
+}
+
+impl<'a> Otp<'a> {
+    ///Length of encrypted token in characters
+    pub const TOKEN_LEN: usize = TOKEN_LEN;
+    ///Maximum length of public ID in characters
+    pub const MAX_PUBLIC_ID_LEN: usize = 32;
+    ///Minimum length of OTP, which has no public ID
+    pub const MIN_LEN: usize = Self::TOKEN_LEN;
+    ///Maximum length of OTP
+    pub const MAX_LEN: usize = Self::TOKEN_LEN + Self::MAX_PUBLIC_ID_LEN;
+
+    ///Parses OTP, validating its length and characters
+    pub fn parse(otp: &'a str) -> Result<Self, OtpError> {
+        if otp.len() < Self::MIN_LEN || otp.len() > Self::MAX_LEN || otp.len() % 2 != 0 {
+            return Err(OtpError::InvalidLength(otp.len()));
+        }
+
+        if let Some(ch) = otp.bytes().find(|ch| ALPHABET.nibble(*ch).is_none()) {
+            return Err(OtpError::InvalidChar(DecodeError::unexpected_char(ch)));
+        }
+
+        let (public_id, token) = otp.split_at(otp.len() - Self::TOKEN_LEN);
+        Ok(Self {
+            public_id,
+            token,
+        })
+    }
+
+    #[inline(always)]
+    ///Returns modhex encoded public ID, identifying YubiKey
+    pub const fn public_id(&self) -> &'a str {
+        self.public_id
+    }
+
+    #[inline(always)]
+    ///Returns modhex encoded token
+    pub const fn token(&self) -> &'a str {
+        self.token
+    }
+
+    ///Decodes public ID into `out`, returning number of bytes written.
+    ///
+    ///Output is truncated by its size, if necessary.
+    pub fn decode_public_id(&self, out: &mut [mem::MaybeUninit<u8>]) -> usize {
+        decode(self.public_id.as_bytes(), out).expect("Validated modhex")
+    }
+
+    ///Decodes encrypted token
+    pub fn decode_token(&self) -> [u8; TOKEN_LEN / 2] {
+        let mut out = [mem::MaybeUninit::uninit(); TOKEN_LEN / 2];
+        decode(self.token.as_bytes(), &mut out).expect("Validated modhex");
+        unsafe {
+            mem::transmute(out)
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use core::{fmt, mem, slice, str};
+use core::iter::FusedIterator;
+
+use crate::{format, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+
+//Number of characters encoded per single write_str call
+const BUFFER_LEN: usize = 256;
+
+#[derive(Copy, Clone)]
+///Iterator over 4-bit values of bytes, starting from high nibble of each byte.
+///
+///Unlike bytes, sequence of nibbles may have odd length, e.g. after skipping first nibble:
+///
+///```rust
+///use based16::Nibbles;
+///
+///let nibbles = Nibbles::new(&[0x1a, 0xbc]).split_at(1).1;
+///assert_eq!(nibbles.collect::<Vec<_>>(), [0xa, 0xb, 0xc]);
+///```
+///
+///`Display` and `LowerHex` render remaining nibbles as lower case hex characters, while `UpperHex` uses upper case.
+///
+///Formatting supports the same flags as [Encoder](crate::Encoder), except precision limits number of nibbles.
+pub struct Nibbles<'a> {
+    data: &'a [u8],
+    //Range of nibble indexes within data
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Nibbles<'a> {
+    #[inline(always)]
+    ///Creates new instance over all nibbles of `data`
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            start: 0,
+            end: data.len().saturating_mul(2),
+        }
+    }
+
+    #[inline(always)]
+    fn nibble(&self, idx: usize) -> u8 {
+        let byte = self.data[idx / 2];
+        if idx % 2 == 0 {
+            byte >> 4
+        } else {
+            byte & 0xf
+        }
+    }
+
+    #[inline]
+    ///Returns nibble at `idx` among remaining nibbles
+    pub fn get(&self, idx: usize) -> Option<u8> {
+        if idx < self.len() {
+            Some(self.nibble(self.start + idx))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    ///Splits remaining nibbles at `mid`, returning nibbles before and starting with `mid`
+    ///
+    ///## Panics
+    ///
+    ///If `mid > len`
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        assert!(mid <= self.len(), "mid > len");
+        let mid = self.start + mid;
+        (
+            Self {
+                data: self.data,
+                start: self.start,
+                end: mid,
+            },
+            Self {
+                data: self.data,
+                start: mid,
+                end: self.end,
+            }
+        )
+    }
+
+    fn fmt_hex(&self, table: &[u8; 16], prefix: bool, quote: &str, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (nibbles, truncated) = match fmt.precision() {
+            Some(precision) if precision < self.len() => (self.split_at(precision).0, true),
+            _ => (*self, false),
+        };
+
+        format::write_hex_with(fmt, prefix, quote, nibbles.len(), truncated, |fmt| nibbles.encode_to_fmt(table, fmt))
+    }
+
+    fn encode_to_fmt(&self, table: &[u8; 16], fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [mem::MaybeUninit::<u8>::uninit(); BUFFER_LEN];
+        let mut cursor = self.start;
+
+        while cursor < self.end {
+            let len = core::cmp::min(self.end - cursor, BUFFER_LEN);
+            for (idx, out) in buffer[..len].iter_mut().enumerate() {
+                *out = mem::MaybeUninit::new(table[self.nibble(cursor + idx) as usize]);
+            }
+            cursor += len;
+
+            let chunk = unsafe {
+                str::from_utf8_unchecked(slice::from_raw_parts(buffer.as_ptr() as *const u8, len))
+            };
+            fmt.write_str(chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Iterator for Nibbles<'_> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.start += 1;
+            Some(self.nibble(self.start - 1))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.start = self.start.saturating_add(n).min(self.end);
+        self.next()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Nibbles<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.end -= 1;
+            Some(self.nibble(self.end))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.end = self.end.saturating_sub(n).max(self.start);
+        self.next_back()
+    }
+}
+
+impl ExactSizeIterator for Nibbles<'_> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+impl FusedIterator for Nibbles<'_> {}
+
+impl fmt::LowerHex for Nibbles<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(CHAR_TABLE_LOWER, fmt.alternate(), "", fmt)
+    }
+}
+
+impl fmt::UpperHex for Nibbles<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(CHAR_TABLE_UPPER, fmt.alternate(), "", fmt)
+    }
+}
+
+impl fmt::Display for Nibbles<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, fmt)
+    }
+}
+
+impl fmt::Debug for Nibbles<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(CHAR_TABLE_LOWER, false, "\"", fmt)
+    }
+}
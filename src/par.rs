@@ -0,0 +1,72 @@
+use core::{cmp, mem};
+
+use rayon::prelude::*;
+
+use crate::{arch, CharTable, DecodeErrorAt, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+use crate::{required_encode_len, required_decode_len};
+
+//Number of input bytes processed by single task when encoding.
+//Decoding processes twice as much characters, producing the same number of bytes.
+const CHUNK_LEN: usize = 64 * 1024;
+
+#[cold]
+#[inline(never)]
+//Locates first invalid character in `input`, which is known to be invalid
+fn find_invalid(input: &[u8], base_offset: usize) -> DecodeErrorAt {
+    let error = crate::validate(input).expect_err("to have invalid character");
+    DecodeErrorAt {
+        offset: base_offset.saturating_add(error.offset),
+        error: error.error,
+    }
+}
+
+fn par_hex(table: CharTable, input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    let len = cmp::min(input.len(), required_decode_len(out.len()));
+    let input = &input[..len];
+    let out = &mut out[..required_encode_len(len)];
+
+    input.par_chunks(CHUNK_LEN).zip(out.par_chunks_mut(required_encode_len(CHUNK_LEN))).for_each(|(input, out)| {
+        arch::hex(table, input, out);
+    });
+
+    required_encode_len(len)
+}
+
+#[inline(always)]
+///Writes upper case hex into `out`, splitting work across rayon thread pool.
+///
+///Output is truncated by `out` size in the same way as [hex_upper](crate::hex_upper).
+pub fn par_hex_upper(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    par_hex(CHAR_TABLE_UPPER, input, out)
+}
+
+#[inline(always)]
+///Writes lower case hex into `out`, splitting work across rayon thread pool.
+///
+///Output is truncated by `out` size in the same way as [hex_lower](crate::hex_lower).
+pub fn par_hex_lower(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    par_hex(CHAR_TABLE_LOWER, input, out)
+}
+
+///Decodes hex-encoded `input` into `out`, splitting work across rayon thread pool.
+///
+///Output is truncated by `out` size in the same way as [unhex](crate::unhex).
+///
+///On error, returns the earliest invalid character within `input`, regardless of order in which chunks are processed.
+pub fn par_unhex(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeErrorAt> {
+    let len = cmp::min(required_decode_len(input.len()), out.len());
+    let input = &input[..required_encode_len(len)];
+    let out = &mut out[..len];
+
+    let error = input.par_chunks(required_encode_len(CHUNK_LEN)).zip(out.par_chunks_mut(CHUNK_LEN)).enumerate().find_map_first(|(idx, (input, out))| {
+        match arch::unhex(input, out) {
+            Ok(_) => None,
+            Err(_) => Some(find_invalid(input, idx * required_encode_len(CHUNK_LEN))),
+        }
+    });
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(len),
+    }
+}
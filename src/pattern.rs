@@ -0,0 +1,250 @@
+//!Byte patterns with wildcards, as used by signature scanners: `48 8B ?? ?? E8 ?? ?? ?? ??`.
+
+use core::{fmt, str};
+use core::iter::FusedIterator;
+
+use crate::{arch, unhex_nibble, unhex_pair, DecodeError, DecodeErrorAt, CHAR_TABLE_UPPER};
+
+const WILDCARD: u8 = b'?';
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Error parsing [Pattern](Pattern)
+pub enum PatternError {
+    ///Pattern has more bytes than capacity, which is specified
+    TooLong(usize),
+    ///Byte consists of single character, with offset of the character
+    IncompleteByte(usize),
+    ///Invalid character encountered
+    InvalidChar(DecodeErrorAt),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong(capacity) => fmt.write_fmt(format_args!("Pattern exceeds capacity of {} bytes", capacity)),
+            Self::IncompleteByte(offset) => fmt.write_fmt(format_args!("Incomplete byte at offset {}", offset)),
+            Self::InvalidChar(error) => fmt::Display::fmt(error, fmt),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatternError {}
+
+#[inline(always)]
+const fn invalid_char(text: &[u8], offset: usize) -> PatternError {
+    PatternError::InvalidChar(DecodeErrorAt {
+        offset,
+        error: DecodeError::InvalidChar(text[offset]),
+    })
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+///Byte pattern of up to `N` bytes, where any nibble can be wildcard.
+///
+///Pattern can be created from literal at compile time:
+///
+///```rust
+///use based16::pattern::Pattern;
+///
+///const CALL: Pattern<16> = Pattern::from_literal("48 8B ?? ?? E8 ?? ?? ?? ??");
+///
+///let code = [0x90, 0x48, 0x8b, 0x05, 0x10, 0xe8, 0x01, 0x02, 0x03, 0x04];
+///assert_eq!(CALL.find(&code), Some(1));
+///```
+pub struct Pattern<const N: usize> {
+    //Bytes with wildcard nibbles set to zero
+    bytes: [u8; N],
+    //Bits that must match
+    masks: [u8; N],
+    len: usize,
+    //Index of the first byte without wildcards, used to find candidates
+    anchor: Option<usize>,
+}
+
+impl<const N: usize> Pattern<N> {
+    ///Parses pattern, where each byte is pair of hex characters in either case or `?` wildcards.
+    ///
+    ///Whitespace between bytes is optional, i.e. `488B??` is the same as `48 8B ??`.
+    ///
+    ///Empty pattern matches at every offset.
+    pub const fn parse(text: &str) -> Result<Self, PatternError> {
+        let text = text.as_bytes();
+        let mut result = Self {
+            bytes: [0; N],
+            masks: [0; N],
+            len: 0,
+            anchor: None,
+        };
+
+        let mut offset = 0;
+        while offset < text.len() {
+            if text[offset].is_ascii_whitespace() {
+                offset += 1;
+                continue;
+            } else if offset + 1 == text.len() || text[offset + 1].is_ascii_whitespace() {
+                return Err(PatternError::IncompleteByte(offset));
+            } else if result.len == N {
+                return Err(PatternError::TooLong(N));
+            }
+
+            let mut pair = [text[offset], text[offset + 1]];
+            let mut mask = u8::MAX;
+            if pair[0] == WILDCARD {
+                pair[0] = b'0';
+                mask &= 0x0f;
+            }
+            if pair[1] == WILDCARD {
+                pair[1] = b'0';
+                mask &= 0xf0;
+            }
+
+            result.bytes[result.len] = match unhex_pair(pair) {
+                Ok(byte) => byte,
+                Err(_) => if unhex_nibble(pair[0]).is_err() {
+                    return Err(invalid_char(text, offset));
+                } else {
+                    return Err(invalid_char(text, offset + 1));
+                },
+            };
+            result.masks[result.len] = mask;
+            if mask == u8::MAX && result.anchor.is_none() {
+                result.anchor = Some(result.len);
+            }
+
+            result.len += 1;
+            offset += 2;
+        }
+
+        Ok(result)
+    }
+
+    ///Parses pattern from literal, intended for use in const context.
+    ///
+    ///## Panics
+    ///
+    ///If `text` is not valid pattern
+    pub const fn from_literal(text: &str) -> Self {
+        match Self::parse(text) {
+            Ok(pattern) => pattern,
+            Err(_) => panic!("Invalid pattern literal"),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of bytes in pattern, which is also length of each match
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    ///Returns whether pattern is empty
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    ///Returns whether `data` starts with match of the pattern
+    pub fn is_prefix_of(&self, data: &[u8]) -> bool {
+        data.len() >= self.len && self.bytes[..self.len].iter().zip(self.masks.iter()).zip(data.iter()).all(|((byte, mask), ch)| ch & mask == *byte)
+    }
+
+    ///Returns offset of the first match within `haystack`
+    pub fn find(&self, haystack: &[u8]) -> Option<usize> {
+        let last = haystack.len().checked_sub(self.len)?;
+
+        match self.anchor {
+            Some(anchor) => {
+                let byte = self.bytes[anchor];
+                let mut start = 0;
+                while start <= last {
+                    let candidate = start + arch::find_byte(&haystack[start + anchor..=last + anchor], byte)?;
+                    if self.is_prefix_of(&haystack[candidate..]) {
+                        return Some(candidate);
+                    }
+                    start = candidate + 1;
+                }
+                None
+            },
+            None => (0..=last).find(|offset| self.is_prefix_of(&haystack[*offset..])),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns iterator over offsets of all matches within `haystack`, including overlapping ones
+    pub fn find_iter<'a>(&'a self, haystack: &'a [u8]) -> Matches<'a, N> {
+        Matches {
+            pattern: self,
+            haystack,
+            cursor: 0,
+        }
+    }
+}
+
+impl<const N: usize> str::FromStr for Pattern<N> {
+    type Err = PatternError;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl<const N: usize> fmt::Display for Pattern<N> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        for (idx, (byte, mask)) in self.bytes[..self.len].iter().zip(self.masks.iter()).enumerate() {
+            if idx > 0 {
+                fmt.write_char(' ')?;
+            }
+            for (nibble, nibble_mask) in [(byte >> 4, mask >> 4), (byte & 0xf, mask & 0xf)] {
+                let ch = if nibble_mask == 0 {
+                    WILDCARD
+                } else {
+                    CHAR_TABLE_UPPER[nibble as usize]
+                };
+                fmt.write_char(ch as char)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::Debug for Pattern<N> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("Pattern(")?;
+        fmt::Display::fmt(self, fmt)?;
+        fmt.write_str(")")
+    }
+}
+
+#[derive(Clone)]
+///Iterator over offsets of [Pattern](Pattern) matches
+pub struct Matches<'a, const N: usize> {
+    pattern: &'a Pattern<N>,
+    haystack: &'a [u8],
+    cursor: usize,
+}
+
+impl<const N: usize> Iterator for Matches<'_, N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let haystack = self.haystack.get(self.cursor..)?;
+        match self.pattern.find(haystack) {
+            Some(offset) => {
+                let offset = self.cursor + offset;
+                self.cursor = offset + 1;
+                Some(offset)
+            },
+            None => {
+                self.cursor = usize::MAX;
+                None
+            },
+        }
+    }
+}
+
+impl<const N: usize> FusedIterator for Matches<'_, N> {}
@@ -0,0 +1,433 @@
+//!Percent-encoding (aka URL encoding), escaping bytes as `%XX` using upper case hex.
+//!
+//!Bytes to escape are selected by [AsciiSet](AsciiSet), while non-ASCII bytes are always escaped.
+//!
+//!Form mode follows `application/x-www-form-urlencoded`, where space is written as `+`.
+
+use core::{convert, fmt, mem, slice, str};
+
+use crate::{const_fn, unhex_pair, CHAR_TABLE_UPPER};
+
+//Number of characters encoded per single write_str call
+const BUFFER_LEN: usize = 256;
+const ESCAPE: u8 = b'%';
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+///Set of ASCII characters that must be escaped during encoding.
+///
+///Sets can be composed at compile time:
+///
+///```rust
+///use based16::percent::{AsciiSet, CONTROLS};
+///
+///const SET: AsciiSet = CONTROLS.add(b' ').add(b'%');
+///assert!(SET.contains(b'%'));
+///assert!(!SET.contains(b'a'));
+///```
+pub struct AsciiSet {
+    mask: [u32; 4],
+}
+
+impl AsciiSet {
+    ///Set without characters
+    pub const EMPTY: Self = Self {
+        mask: [0; 4],
+    };
+
+    #[inline(always)]
+    ///Returns whether `ch` is in the set.
+    ///
+    ///Non-ASCII bytes are never in the set.
+    pub const fn contains(&self, ch: u8) -> bool {
+        ch.is_ascii() && (self.mask[(ch / 32) as usize] & (1 << (ch % 32))) != 0
+    }
+
+    ///Returns new set with `ch` added.
+    ///
+    ///## Panics
+    ///
+    ///If `ch` is not ASCII character
+    pub const fn add(&self, ch: u8) -> Self {
+        assert!(ch.is_ascii(), "ch is not ASCII");
+        let mut mask = self.mask;
+        mask[(ch / 32) as usize] |= 1 << (ch % 32);
+        Self {
+            mask
+        }
+    }
+
+    ///Returns new set with `ch` removed.
+    pub const fn remove(&self, ch: u8) -> Self {
+        if !ch.is_ascii() {
+            return *self;
+        }
+
+        let mut mask = self.mask;
+        mask[(ch / 32) as usize] &= !(1 << (ch % 32));
+        Self {
+            mask
+        }
+    }
+
+    ///Returns union of both sets.
+    pub const fn union(&self, other: &Self) -> Self {
+        Self {
+            mask: [
+                self.mask[0] | other.mask[0],
+                self.mask[1] | other.mask[1],
+                self.mask[2] | other.mask[2],
+                self.mask[3] | other.mask[3],
+            ]
+        }
+    }
+
+    #[inline(always)]
+    const fn should_escape(&self, ch: u8) -> bool {
+        !ch.is_ascii() || self.contains(ch)
+    }
+}
+
+///C0 control characters and DEL
+pub const CONTROLS: AsciiSet = AsciiSet {
+    mask: [u32::MAX, 0, 0, 1 << 31],
+};
+
+///Everything, except ASCII letters and digits
+pub const NON_ALPHANUMERIC: AsciiSet = AsciiSet {
+    mask: [u32::MAX, !0x03ff0000, !0x07fffffe, !0x07fffffe],
+};
+
+///URL fragment set as defined by WHATWG URL standard
+pub const FRAGMENT: AsciiSet = CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
+
+///URL query set as defined by WHATWG URL standard
+pub const QUERY: AsciiSet = CONTROLS.add(b' ').add(b'"').add(b'#').add(b'<').add(b'>');
+
+///URL path set as defined by WHATWG URL standard
+pub const PATH: AsciiSet = QUERY.add(b'?').add(b'`').add(b'{').add(b'}');
+
+///URL userinfo set as defined by WHATWG URL standard
+pub const USERINFO: AsciiSet = PATH.add(b'/').add(b':').add(b';').add(b'=').add(b'@').add(b'[').add(b'\\').add(b']').add(b'^').add(b'|');
+
+///URI component set as defined by WHATWG URL standard, matching `encodeURIComponent`
+pub const COMPONENT: AsciiSet = USERINFO.add(b'$').add(b'%').add(b'&').add(b'+').add(b',');
+
+///`application/x-www-form-urlencoded` set as defined by WHATWG URL standard
+pub const FORM: AsciiSet = COMPONENT.add(b'!').add(b'\'').add(b'(').add(b')').add(b'~');
+
+///Length sufficient to percent-encode input of `len` bytes
+pub const fn required_encode_len(len: usize) -> usize {
+    len.saturating_mul(3)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Error indicating `%` not followed by two hex characters
+pub struct MalformedEscape {
+    ///Offset of `%` within input
+    pub offset: usize,
+}
+
+impl fmt::Display for MalformedEscape {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("Malformed percent escape at offset {}", self.offset))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MalformedEscape {}
+
+//Returns number of consumed input bytes and number of written characters
+fn encode_with(set: &AsciiSet, form: bool, input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> (usize, usize) {
+    let mut written = 0;
+
+    for (idx, ch) in input.iter().enumerate() {
+        let ch = *ch;
+        if form && ch == b' ' {
+            match out.get_mut(written) {
+                Some(out) => *out = mem::MaybeUninit::new(b'+'),
+                None => return (idx, written),
+            }
+            written += 1;
+        } else if set.should_escape(ch) {
+            let out = match out.get_mut(written..written + 3) {
+                Some(out) => out,
+                None => return (idx, written),
+            };
+            let pair = const_fn::dec2hex(CHAR_TABLE_UPPER, ch);
+            out[0] = mem::MaybeUninit::new(ESCAPE);
+            out[1] = mem::MaybeUninit::new(pair.0[0]);
+            out[2] = mem::MaybeUninit::new(pair.0[1]);
+            written += 3;
+        } else {
+            match out.get_mut(written) {
+                Some(out) => *out = mem::MaybeUninit::new(ch),
+                None => return (idx, written),
+            }
+            written += 1;
+        }
+    }
+
+    (input.len(), written)
+}
+
+#[inline(always)]
+fn strict_escape(offset: usize) -> Result<u8, MalformedEscape> {
+    Err(MalformedEscape {
+        offset,
+    })
+}
+
+#[inline(always)]
+fn lenient_escape(_: usize) -> Result<u8, convert::Infallible> {
+    Ok(ESCAPE)
+}
+
+//Returns number of written bytes.
+//
+//`on_malformed` is called with offset of malformed escape, returning byte to write instead of `%` or error.
+fn decode_with<E, F: FnMut(usize) -> Result<u8, E>>(form: bool, input: &[u8], out: &mut [mem::MaybeUninit<u8>], mut on_malformed: F) -> Result<usize, E> {
+    let mut idx = 0;
+    let mut written = 0;
+
+    while idx < input.len() && written < out.len() {
+        let ch = input[idx];
+        let byte = if ch == ESCAPE {
+            let escape = input.get(idx + 1..idx + 3).map(|pair| unhex_pair([pair[0], pair[1]]));
+            match escape {
+                Some(Ok(byte)) => {
+                    idx += 2;
+                    byte
+                },
+                _ => on_malformed(idx)?,
+            }
+        } else if form && ch == b'+' {
+            b' '
+        } else {
+            ch
+        };
+
+        out[written] = mem::MaybeUninit::new(byte);
+        written += 1;
+        idx += 1;
+    }
+
+    Ok(written)
+}
+
+#[inline(always)]
+///Percent-encodes `input` into `out`, escaping non-ASCII bytes and characters within `set`.
+///
+///Output is truncated by its size, if necessary, without splitting escape sequences.
+///
+///Returns number of characters written.
+pub fn encode(input: &[u8], set: &AsciiSet, out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    encode_with(set, false, input, out).1
+}
+
+#[inline(always)]
+///Encodes `input` into `out` as `application/x-www-form-urlencoded`, escaping with [FORM](FORM) set and writing space as `+`.
+///
+///Output is truncated by its size, if necessary, without splitting escape sequences.
+///
+///Returns number of characters written.
+pub fn encode_form(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    encode_with(&FORM, true, input, out).1
+}
+
+#[inline(always)]
+///Decodes percent-encoded `input` into `out`, truncating by its size, if necessary.
+///
+///Fails if `%` is not followed by two hex characters.
+pub fn decode(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, MalformedEscape> {
+    decode_with(false, input, out, strict_escape)
+}
+
+#[inline(always)]
+///Decodes percent-encoded `input` into `out`, truncating by its size, if necessary.
+///
+///Malformed escape sequences are written as is.
+pub fn decode_lenient(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    match decode_with(false, input, out, lenient_escape) {
+        Ok(len) => len,
+        Err(never) => match never {},
+    }
+}
+
+#[inline(always)]
+///Decodes `application/x-www-form-urlencoded` `input` into `out`, truncating by its size, if necessary.
+///
+///`+` is decoded as space, and it fails if `%` is not followed by two hex characters.
+pub fn decode_form(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, MalformedEscape> {
+    decode_with(true, input, out, strict_escape)
+}
+
+#[inline(always)]
+///Decodes `application/x-www-form-urlencoded` `input` into `out`, truncating by its size, if necessary.
+///
+///`+` is decoded as space, and malformed escape sequences are written as is.
+pub fn decode_form_lenient(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    match decode_with(true, input, out, lenient_escape) {
+        Ok(len) => len,
+        Err(never) => match never {},
+    }
+}
+
+#[derive(Copy, Clone)]
+///Percent-encoding formatting adapter.
+///
+///`Display` implementation renders encoded data, issuing single `write_str` per block of input.
+pub struct Encoder<'a> {
+    data: &'a [u8],
+    set: &'a AsciiSet,
+    form: bool,
+}
+
+impl<'a> Encoder<'a> {
+    #[inline(always)]
+    ///Creates new instance escaping non-ASCII bytes and characters within `set`
+    pub const fn new(data: &'a [u8], set: &'a AsciiSet) -> Self {
+        Self {
+            data,
+            set,
+            form: false,
+        }
+    }
+
+    #[inline(always)]
+    ///Creates new instance encoding as `application/x-www-form-urlencoded`
+    pub const fn form(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            set: &FORM,
+            form: true,
+        }
+    }
+}
+
+impl fmt::Display for Encoder<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [mem::MaybeUninit::<u8>::uninit(); BUFFER_LEN];
+        let mut data = self.data;
+
+        while !data.is_empty() {
+            let (consumed, len) = encode_with(self.set, self.form, data, &mut buffer);
+            data = &data[consumed..];
+            let chunk = unsafe {
+                str::from_utf8_unchecked(slice::from_raw_parts(buffer.as_ptr() as *const u8, len))
+            };
+            fmt.write_str(chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Encoder<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("\"")?;
+        fmt::Display::fmt(self, fmt)?;
+        fmt.write_str("\"")
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc {
+    extern crate alloc;
+
+    use alloc::borrow::Cow;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::str;
+
+    use super::*;
+
+    fn encode_cow_with<'a>(set: &AsciiSet, form: bool, input: &'a [u8]) -> Cow<'a, str> {
+        let escape_count = input.iter().filter(|ch| set.should_escape(**ch)).count();
+        let is_plain = escape_count == 0 && !(form && input.contains(&b' '));
+        if is_plain {
+            //Only ASCII characters remain unescaped
+            return Cow::Borrowed(unsafe {
+                str::from_utf8_unchecked(input)
+            });
+        }
+
+        let mut out = Vec::with_capacity(input.len() + escape_count * 2);
+        let (_, len) = encode_with(set, form, input, out.spare_capacity_mut());
+        unsafe {
+            out.set_len(len);
+            Cow::Owned(String::from_utf8_unchecked(out))
+        }
+    }
+
+    fn decode_cow_with<E, F: FnMut(usize) -> Result<u8, E>>(form: bool, input: &[u8], on_malformed: F) -> Result<Cow<'_, [u8]>, E> {
+        let is_encoded = input.contains(&ESCAPE) || (form && input.contains(&b'+'));
+        if !is_encoded {
+            return Ok(Cow::Borrowed(input));
+        }
+
+        let mut out = Vec::with_capacity(input.len());
+        let len = decode_with(form, input, out.spare_capacity_mut(), on_malformed)?;
+        unsafe {
+            out.set_len(len);
+        }
+        Ok(Cow::Owned(out))
+    }
+
+    #[inline(always)]
+    ///Percent-encodes `input`, escaping non-ASCII bytes and characters within `set`.
+    ///
+    ///Returns borrowed `input` if nothing needs escaping.
+    pub fn encode_to_cow<'a>(input: &'a [u8], set: &AsciiSet) -> Cow<'a, str> {
+        encode_cow_with(set, false, input)
+    }
+
+    #[inline(always)]
+    ///Encodes `input` as `application/x-www-form-urlencoded`.
+    ///
+    ///Returns borrowed `input` if nothing needs escaping.
+    pub fn encode_form_to_cow(input: &[u8]) -> Cow<'_, str> {
+        encode_cow_with(&FORM, true, input)
+    }
+
+    #[inline(always)]
+    ///Decodes percent-encoded `input`, failing on malformed escape sequence.
+    ///
+    ///Returns borrowed `input` if nothing needs decoding.
+    pub fn decode_to_cow(input: &[u8]) -> Result<Cow<'_, [u8]>, MalformedEscape> {
+        decode_cow_with(false, input, strict_escape)
+    }
+
+    #[inline(always)]
+    ///Decodes percent-encoded `input`, writing malformed escape sequences as is.
+    ///
+    ///Returns borrowed `input` if nothing needs decoding.
+    pub fn decode_lenient_to_cow(input: &[u8]) -> Cow<'_, [u8]> {
+        match decode_cow_with(false, input, lenient_escape) {
+            Ok(res) => res,
+            Err(never) => match never {},
+        }
+    }
+
+    #[inline(always)]
+    ///Decodes `application/x-www-form-urlencoded` `input`, failing on malformed escape sequence.
+    ///
+    ///Returns borrowed `input` if nothing needs decoding.
+    pub fn decode_form_to_cow(input: &[u8]) -> Result<Cow<'_, [u8]>, MalformedEscape> {
+        decode_cow_with(true, input, strict_escape)
+    }
+
+    #[inline(always)]
+    ///Decodes `application/x-www-form-urlencoded` `input`, writing malformed escape sequences as is.
+    ///
+    ///Returns borrowed `input` if nothing needs decoding.
+    pub fn decode_form_lenient_to_cow(input: &[u8]) -> Cow<'_, [u8]> {
+        match decode_cow_with(true, input, lenient_escape) {
+            Ok(res) => res,
+            Err(never) => match never {},
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use self::alloc::*;
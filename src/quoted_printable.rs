@@ -0,0 +1,307 @@
+//!Quoted-printable encoding as defined by RFC 2045, escaping bytes as `=XX` using upper case hex.
+//!
+//!Also provides `Q` encoding variant of RFC 2047, used in encoded words of mail headers.
+
+use core::{fmt, mem, slice, str};
+
+use crate::{const_fn, unhex_pair, CHAR_TABLE_UPPER};
+
+//Number of characters encoded per single write_str call
+const BUFFER_LEN: usize = 256;
+const ESCAPE: u8 = b'=';
+const LINE_BREAK: &[u8] = b"\r\n";
+const SOFT_LINE_BREAK: &[u8] = b"=\r\n";
+///Maximum length of encoded line, excluding line break
+pub const MAX_LINE_LEN: usize = 76;
+
+///Length sufficient to encode input of `len` bytes
+pub const fn required_encode_len(len: usize) -> usize {
+    let len = len.saturating_mul(3);
+    //Line is broken only when it has at least `MAX_LINE_LEN - 3` characters
+    let line_breaks = len / (MAX_LINE_LEN - 3) + 1;
+    len.saturating_add(line_breaks.saturating_mul(SOFT_LINE_BREAK.len()))
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Error indicating `=` followed by neither two hex characters nor line break
+pub struct MalformedEscape {
+    ///Offset of `=` within input
+    pub offset: usize,
+}
+
+impl fmt::Display for MalformedEscape {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("Malformed quoted-printable escape at offset {}", self.offset))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MalformedEscape {}
+
+#[inline(always)]
+fn escape_byte(byt: u8) -> [u8; 3] {
+    let pair = const_fn::dec2hex(CHAR_TABLE_UPPER, byt);
+    [ESCAPE, pair.0[0], pair.0[1]]
+}
+
+#[inline(always)]
+fn write_chars(out: &mut [mem::MaybeUninit<u8>], chars: &[u8]) {
+    for (out, ch) in out.iter_mut().zip(chars.iter()) {
+        *out = mem::MaybeUninit::new(*ch);
+    }
+}
+
+#[inline(always)]
+fn is_line_end(input: &[u8]) -> bool {
+    input.is_empty() || input.starts_with(LINE_BREAK)
+}
+
+//Returns number of consumed input bytes and number of written characters
+fn encode_with(column: &mut usize, input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> (usize, usize) {
+    let mut idx = 0;
+    let mut written = 0;
+
+    while idx < input.len() {
+        if input[idx..].starts_with(LINE_BREAK) {
+            match out.get_mut(written..written + LINE_BREAK.len()) {
+                Some(out) => write_chars(out, LINE_BREAK),
+                None => break,
+            }
+            written += LINE_BREAK.len();
+            idx += LINE_BREAK.len();
+            *column = 0;
+            continue;
+        }
+
+        let byt = input[idx];
+        let is_last = is_line_end(&input[idx + 1..]);
+        let escaped;
+        let chars = match byt {
+            b'!'..=b'~' if byt != ESCAPE => slice::from_ref(&input[idx]),
+            //Whitespace at the end of line must be encoded
+            b' ' | b'\t' if !is_last => slice::from_ref(&input[idx]),
+            byt => {
+                escaped = escape_byte(byt);
+                &escaped[..]
+            }
+        };
+
+        //Last character of line may take space of soft line break
+        let max_len = if is_last {
+            MAX_LINE_LEN
+        } else {
+            MAX_LINE_LEN - 1
+        };
+        let soft_break = *column + chars.len() > max_len;
+        let len = if soft_break {
+            SOFT_LINE_BREAK.len() + chars.len()
+        } else {
+            chars.len()
+        };
+        let out = match out.get_mut(written..written + len) {
+            Some(out) => out,
+            None => break,
+        };
+
+        if soft_break {
+            write_chars(out, SOFT_LINE_BREAK);
+            write_chars(&mut out[SOFT_LINE_BREAK.len()..], chars);
+            *column = chars.len();
+        } else {
+            write_chars(out, chars);
+            *column += chars.len();
+        }
+        written += len;
+        idx += 1;
+    }
+
+    (idx, written)
+}
+
+//Returns number of consumed input bytes and number of written characters
+fn encode_q_with(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> (usize, usize) {
+    let mut written = 0;
+
+    for (idx, byt) in input.iter().enumerate() {
+        let escaped;
+        let chars = match byt {
+            b' ' => b"_",
+            b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'!' | b'*' | b'+' | b'-' | b'/' => slice::from_ref(byt),
+            byt => {
+                escaped = escape_byte(*byt);
+                &escaped[..]
+            }
+        };
+
+        match out.get_mut(written..written + chars.len()) {
+            Some(out) => write_chars(out, chars),
+            None => return (idx, written),
+        }
+        written += chars.len();
+    }
+
+    (input.len(), written)
+}
+
+#[inline]
+///Encodes `input` into `out` as quoted-printable, returning number of characters written.
+///
+///`CRLF` within `input` is preserved as hard line break, while other control characters are escaped.
+///Whitespace at the end of line is escaped and lines longer than [MAX_LINE_LEN](MAX_LINE_LEN) are split with soft line breaks.
+///
+///Output is truncated by its size, if necessary, without splitting escape sequences.
+pub fn encode(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    encode_with(&mut 0, input, out).1
+}
+
+#[inline]
+///Encodes `input` into `out` using RFC 2047 `Q` encoding, returning number of characters written.
+///
+///Space is written as `_` and everything, except letters, digits and `!*+-/`, is escaped.
+///Output has no line breaks, as splitting into encoded words is up to the user.
+///
+///Output is truncated by its size, if necessary, without splitting escape sequences.
+pub fn encode_q(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> usize {
+    encode_q_with(input, out).1
+}
+
+///Decodes quoted-printable `input` into `out`, truncating by its size, if necessary.
+///
+///Soft line breaks and whitespace at the end of lines are removed. Both `CRLF` and `LF` line breaks are accepted.
+///`=` at the end of input is treated as soft line break.
+pub fn decode(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, MalformedEscape> {
+    let mut idx = 0;
+    let mut written = 0;
+
+    while idx < input.len() && written < out.len() {
+        let byt = input[idx];
+        match byt {
+            ESCAPE => {
+                if let Some(Ok(byt)) = input.get(idx + 1..idx + 3).map(|pair| unhex_pair([pair[0], pair[1]])) {
+                    out[written] = mem::MaybeUninit::new(byt);
+                    written += 1;
+                    idx += 3;
+                    continue;
+                }
+
+                //Soft line break, possibly with transport padding
+                let rest = &input[idx + 1..];
+                let padding = rest.iter().take_while(|ch| matches!(ch, b' ' | b'\t')).count();
+                let rest = &rest[padding..];
+                let line_break = if rest.is_empty() {
+                    0
+                } else if rest.starts_with(LINE_BREAK) {
+                    LINE_BREAK.len()
+                } else if rest[0] == b'\n' {
+                    1
+                } else {
+                    return Err(MalformedEscape {
+                        offset: idx,
+                    });
+                };
+                idx += 1 + padding + line_break;
+            },
+            b' ' | b'\t' => {
+                let rest = &input[idx..];
+                let whitespace = rest.iter().take_while(|ch| matches!(ch, b' ' | b'\t')).count();
+                let rest = &rest[whitespace..];
+                if is_line_end(rest) || rest[0] == b'\n' {
+                    idx += whitespace;
+                } else {
+                    out[written] = mem::MaybeUninit::new(byt);
+                    written += 1;
+                    idx += 1;
+                }
+            },
+            byt => {
+                out[written] = mem::MaybeUninit::new(byt);
+                written += 1;
+                idx += 1;
+            }
+        }
+    }
+
+    Ok(written)
+}
+
+///Decodes RFC 2047 `Q` encoded `input` into `out`, truncating by its size, if necessary.
+///
+///`_` is decoded as space.
+pub fn decode_q(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, MalformedEscape> {
+    let mut idx = 0;
+    let mut written = 0;
+
+    while idx < input.len() && written < out.len() {
+        let byt = match input[idx] {
+            ESCAPE => match input.get(idx + 1..idx + 3).map(|pair| unhex_pair([pair[0], pair[1]])) {
+                Some(Ok(byt)) => {
+                    idx += 2;
+                    byt
+                },
+                _ => return Err(MalformedEscape {
+                    offset: idx,
+                }),
+            },
+            b'_' => b' ',
+            byt => byt,
+        };
+
+        out[written] = mem::MaybeUninit::new(byt);
+        written += 1;
+        idx += 1;
+    }
+
+    Ok(written)
+}
+
+#[derive(Copy, Clone)]
+///Quoted-printable formatting adapter.
+///
+///`Display` implementation renders encoded data, issuing single `write_str` per block of input.
+pub struct Encoder<'a> {
+    data: &'a [u8],
+    q: bool,
+}
+
+impl<'a> Encoder<'a> {
+    #[inline(always)]
+    ///Creates new instance, encoding as per [encode](encode)
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            q: false,
+        }
+    }
+
+    #[inline(always)]
+    ///Creates new instance, encoding as per [encode_q](encode_q)
+    pub const fn q(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            q: true,
+        }
+    }
+}
+
+impl fmt::Display for Encoder<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [mem::MaybeUninit::<u8>::uninit(); BUFFER_LEN];
+        let mut column = 0;
+        let mut data = self.data;
+
+        while !data.is_empty() {
+            let (consumed, len) = if self.q {
+                encode_q_with(data, &mut buffer)
+            } else {
+                encode_with(&mut column, data, &mut buffer)
+            };
+            data = &data[consumed..];
+            let chunk = unsafe {
+                str::from_utf8_unchecked(slice::from_raw_parts(buffer.as_ptr() as *const u8, len))
+            };
+            fmt.write_str(chunk)?;
+        }
+
+        Ok(())
+    }
+}
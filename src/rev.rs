@@ -0,0 +1,210 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::{format, dec2hex, required_encode_len, required_decode_len, unhex_pair, CharPair, CharTable, DecodeError, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+
+///Hex encoder producing bytes in reverse order, starting from the last one.
+///
+///Useful for little-endian representations such as Bitcoin transaction ids.
+///
+///`Display` implementation renders current data in reverse order without advancing iterator, supporting the same flags as [Encoder](crate::Encoder).
+pub struct RevEncoder<'a> {
+    table: CharTable,
+    data: &'a [u8],
+}
+
+impl<'a> RevEncoder<'a> {
+    #[inline(always)]
+    ///Creates encoder with upper character set
+    pub const fn upper(data: &'a [u8]) -> Self {
+        Self {
+            table: CHAR_TABLE_UPPER,
+            data,
+        }
+    }
+
+    #[inline(always)]
+    ///Creates encoder with lower character set
+    pub const fn lower(data: &'a [u8]) -> Self {
+        Self {
+            table: CHAR_TABLE_LOWER,
+            data,
+        }
+    }
+
+    #[inline(always)]
+    ///Get next byte encoded, going from the end of data
+    pub fn next_byte(&mut self) -> Option<CharPair> {
+        match self.data.split_last() {
+            Some((byt, rest)) => {
+                self.data = rest;
+                Some(dec2hex(self.table, *byt))
+            },
+            None => None
+        }
+    }
+
+    #[inline(always)]
+    ///Get byte encoded from the start of data
+    pub fn next_byte_back(&mut self) -> Option<CharPair> {
+        match self.data.split_first() {
+            Some((byt, rest)) => {
+                self.data = rest;
+                Some(dec2hex(self.table, *byt))
+            },
+            None => None
+        }
+    }
+
+    #[inline(always)]
+    ///Returns remaining data, that is not encoded yet, in original order
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl Iterator for RevEncoder<'_> {
+    type Item = CharPair;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_byte()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.data.len(), Some(self.data.len()))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.data = &self.data[..self.data.len().saturating_sub(n)];
+        self.next_byte()
+    }
+}
+
+impl DoubleEndedIterator for RevEncoder<'_> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_byte_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.data = self.data.get(n..).unwrap_or(&[]);
+        self.next_byte_back()
+    }
+}
+
+impl FusedIterator for RevEncoder<'_> {}
+
+impl ExactSizeIterator for RevEncoder<'_> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl fmt::Display for RevEncoder<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format::fmt_hex(self.table, self.data, true, fmt.alternate(), "", fmt)
+    }
+}
+
+impl fmt::Debug for RevEncoder<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format::fmt_hex(self.table, self.data, true, false, "\"", fmt)
+    }
+}
+
+///Decoder that transforms pairs of characters into individual decimal bytes, starting from the last pair.
+pub struct RevDecoder<'a>(&'a [u8]);
+
+impl<'a> RevDecoder<'a> {
+    #[inline(always)]
+    ///Creates new instance validating that input has even length.
+    pub const fn new(data: &'a str) -> Option<Self> {
+        if data.len() % 2 != 0 {
+            None
+        } else {
+            Some(Self(data.as_bytes()))
+        }
+    }
+
+    #[inline]
+    ///Gets next byte from the end of input, returning error in case of invalid character
+    pub fn next_byte(&mut self) -> Option<Result<u8, DecodeError>> {
+        match self.0.len().checked_sub(2) {
+            Some(idx) => {
+                let chunk = [self.0[idx], self.0[idx + 1]];
+                self.0 = &self.0[..idx];
+                Some(unhex_pair(chunk))
+            },
+            None => None,
+        }
+    }
+
+    #[inline]
+    ///Gets byte from the start of input, returning error in case of invalid character
+    pub fn next_byte_back(&mut self) -> Option<Result<u8, DecodeError>> {
+        match self.0 {
+            [left, right, rest @ ..] => {
+                let chunk = [*left, *right];
+                self.0 = rest;
+                Some(unhex_pair(chunk))
+            },
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns remaining hex input, that is not decoded yet, in original order
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl Iterator for RevDecoder<'_> {
+    type Item = Result<u8, DecodeError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_byte()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0 = &self.0[..self.0.len().saturating_sub(required_encode_len(n))];
+        self.next_byte()
+    }
+}
+
+impl DoubleEndedIterator for RevDecoder<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_byte_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.0 = self.0.get(required_encode_len(n)..).unwrap_or(&[]);
+        self.next_byte_back()
+    }
+}
+
+impl ExactSizeIterator for RevDecoder<'_> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        required_decode_len(self.0.len())
+    }
+}
+
+impl FusedIterator for RevDecoder<'_> {}
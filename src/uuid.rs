@@ -0,0 +1,235 @@
+//!UUID text forms, encoding `[u8; 16]` as grouped hex and parsing it back.
+
+use core::{fmt, mem, ops, str};
+
+use crate::{arch, Alphabet, DecodeError, DecodeErrorAt, CharTable, CHAR_TABLE_LOWER, CHAR_TABLE_UPPER};
+
+const HEX_LEN: usize = 32;
+//Length of hyphen separated groups in characters
+const GROUPS: [usize; 5] = [8, 4, 4, 4, 12];
+const URN_PREFIX: &str = "urn:uuid:";
+
+///Length of `67e55044-10b1-426f-9247-bb680e5fe0c8` form
+pub const HYPHENATED_LEN: usize = 36;
+///Length of `67e5504410b1426f9247bb680e5fe0c8` form
+pub const SIMPLE_LEN: usize = HEX_LEN;
+///Length of `{67e55044-10b1-426f-9247-bb680e5fe0c8}` form
+pub const BRACED_LEN: usize = HYPHENATED_LEN + 2;
+///Length of `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8` form
+pub const URN_LEN: usize = HYPHENATED_LEN + URN_PREFIX.len();
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+///UUID text form
+pub enum Format {
+    ///`67e55044-10b1-426f-9247-bb680e5fe0c8`
+    Hyphenated,
+    ///`67e5504410b1426f9247bb680e5fe0c8`
+    Simple,
+    ///`{67e55044-10b1-426f-9247-bb680e5fe0c8}`
+    Braced,
+    ///`urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`
+    Urn,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Error parsing UUID
+pub enum UuidError {
+    ///Input without hyphens must have exactly 32 hex characters
+    InvalidLength(usize),
+    ///Braced form is missing closing brace
+    UnclosedBrace,
+    ///Hyphenated form must have exactly 5 groups
+    InvalidGroupCount(usize),
+    ///Group of hyphenated form has unexpected length, which indicates misplaced hyphen
+    InvalidGroupLength {
+        ///Index of group, starting from 0
+        group: usize,
+        ///Expected number of characters
+        expected: usize,
+        ///Actual number of characters
+        found: usize,
+    },
+    ///Invalid character encountered, with offset within whole input
+    InvalidChar(DecodeErrorAt),
+}
+
+impl fmt::Display for UuidError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => fmt.write_fmt(format_args!("Invalid UUID length {}, expected 32 hex characters", len)),
+            Self::UnclosedBrace => fmt.write_str("UUID is missing closing brace"),
+            Self::InvalidGroupCount(count) => fmt.write_fmt(format_args!("Invalid number of UUID groups {}, expected 5", count)),
+            Self::InvalidGroupLength { group, expected, found } => fmt.write_fmt(format_args!("UUID group {} has {} characters, expected {}", group, found, expected)),
+            Self::InvalidChar(error) => fmt::Display::fmt(error, fmt),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UuidError {}
+
+#[derive(Copy, Clone)]
+///Encoded UUID text, that can be used as `str`
+pub struct Buffer {
+    buffer: [u8; URN_LEN],
+    len: u8,
+}
+
+impl Buffer {
+    #[inline(always)]
+    ///Returns encoded text
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            str::from_utf8_unchecked(&self.buffer[..self.len as usize])
+        }
+    }
+
+    #[inline(always)]
+    fn push(&mut self, text: &[u8]) {
+        let len = self.len as usize;
+        self.buffer[len..len + text.len()].copy_from_slice(text);
+        self.len += text.len() as u8;
+    }
+}
+
+impl ops::Deref for Buffer {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Buffer {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Buffer {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), fmt)
+    }
+}
+
+impl fmt::Debug for Buffer {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), fmt)
+    }
+}
+
+fn encode(table: CharTable, uuid: &[u8; 16], format: Format) -> Buffer {
+    let mut hex = [mem::MaybeUninit::<u8>::uninit(); HEX_LEN];
+    arch::hex(table, uuid, &mut hex);
+    let hex: [u8; HEX_LEN] = unsafe {
+        mem::transmute(hex)
+    };
+
+    let mut result = Buffer {
+        buffer: [0; URN_LEN],
+        len: 0,
+    };
+
+    match format {
+        Format::Simple => {
+            result.push(&hex);
+            return result;
+        },
+        Format::Braced => result.push(b"{"),
+        Format::Urn => result.push(URN_PREFIX.as_bytes()),
+        Format::Hyphenated => (),
+    }
+
+    let mut hex = &hex[..];
+    for (idx, group) in GROUPS.iter().enumerate() {
+        if idx > 0 {
+            result.push(b"-");
+        }
+        let (group, rest) = hex.split_at(*group);
+        result.push(group);
+        hex = rest;
+    }
+
+    if let Format::Braced = format {
+        result.push(b"}");
+    }
+
+    result
+}
+
+#[inline(always)]
+///Encodes `uuid` as lower case text of specified `format`
+pub fn encode_lower(uuid: &[u8; 16], format: Format) -> Buffer {
+    encode(CHAR_TABLE_LOWER, uuid, format)
+}
+
+#[inline(always)]
+///Encodes `uuid` as upper case text of specified `format`
+///
+///URN prefix is always lower case.
+pub fn encode_upper(uuid: &[u8; 16], format: Format) -> Buffer {
+    encode(CHAR_TABLE_UPPER, uuid, format)
+}
+
+#[cold]
+#[inline(never)]
+fn invalid_char(input: &[u8], offset: usize) -> UuidError {
+    //Only hyphens at valid positions are remaining
+    let (idx, ch) = input.iter().enumerate().skip(offset).find(|(_, ch)| **ch != b'-' && Alphabet::LOWER.nibble(**ch).is_none()).expect("to have invalid character");
+    UuidError::InvalidChar(DecodeErrorAt {
+        offset: idx,
+        error: DecodeError::InvalidChar(*ch),
+    })
+}
+
+///Parses UUID in any of [Format](Format) forms, accepting hex characters in either case.
+pub fn decode(input: &str) -> Result<[u8; 16], UuidError> {
+    let input = input.as_bytes();
+    let (offset, text) = if let Some(text) = input.strip_prefix(b"{") {
+        match text.strip_suffix(b"}") {
+            Some(text) => (1, text),
+            None => return Err(UuidError::UnclosedBrace),
+        }
+    } else if input.len() >= URN_PREFIX.len() && input[..URN_PREFIX.len()].eq_ignore_ascii_case(URN_PREFIX.as_bytes()) {
+        (URN_PREFIX.len(), &input[URN_PREFIX.len()..])
+    } else {
+        (0, input)
+    };
+
+    let mut hex = [0u8; HEX_LEN];
+    if text.contains(&b'-') {
+        let count = text.split(|ch| *ch == b'-').count();
+        if count != GROUPS.len() {
+            return Err(UuidError::InvalidGroupCount(count));
+        }
+
+        let mut len = 0;
+        for (group, (text, expected)) in text.split(|ch| *ch == b'-').zip(GROUPS.iter()).enumerate() {
+            if text.len() != *expected {
+                return Err(UuidError::InvalidGroupLength {
+                    group,
+                    expected: *expected,
+                    found: text.len(),
+                });
+            }
+            hex[len..len + text.len()].copy_from_slice(text);
+            len += text.len();
+        }
+    } else if text.len() == HEX_LEN {
+        hex.copy_from_slice(text);
+    } else {
+        return Err(UuidError::InvalidLength(text.len()));
+    }
+
+    let mut uuid = [mem::MaybeUninit::<u8>::uninit(); 16];
+    match arch::unhex(&hex, &mut uuid) {
+        Ok(_) => Ok(unsafe {
+            mem::transmute::<[mem::MaybeUninit<u8>; 16], [u8; 16]>(uuid)
+        }),
+        Err(_) => Err(invalid_char(input, offset)),
+    }
+}
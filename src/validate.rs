@@ -0,0 +1,104 @@
+use core::{mem, str};
+
+use crate::{arch, DecodeError, DecodeErrorAt};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+///Case of letters within hex string
+pub enum Case {
+    ///Only lower case letters are present
+    Lower,
+    ///Only upper case letters are present
+    Upper,
+    ///Letters of both cases are present
+    Mixed,
+    ///No letters are present, including empty string
+    DigitsOnly,
+}
+
+#[cold]
+#[inline(never)]
+fn invalid_char(input: &[u8], offset: usize) -> DecodeErrorAt {
+    DecodeErrorAt {
+        offset,
+        error: DecodeError::unexpected_char(input[offset]),
+    }
+}
+
+#[inline(always)]
+///Returns offset of the first non-hex character in `input`, if any
+pub fn find_invalid(input: &[u8]) -> Option<usize> {
+    arch::scan(input).err()
+}
+
+#[inline(always)]
+///Returns whether `input` consists of hex characters only.
+///
+///Length is not checked, hence odd length input is considered valid.
+pub fn is_hex(input: &[u8]) -> bool {
+    arch::scan(input).is_ok()
+}
+
+#[inline]
+///Validates that `input` consists of hex characters only, without decoding it.
+///
+///Length is not checked, hence odd length input is considered valid.
+pub fn validate(input: &[u8]) -> Result<(), DecodeErrorAt> {
+    match arch::scan(input) {
+        Ok(_) => Ok(()),
+        Err(offset) => Err(invalid_char(input, offset)),
+    }
+}
+
+#[inline]
+///Validates `input`, determining case of its letters.
+pub fn hex_case(input: &[u8]) -> Result<Case, DecodeErrorAt> {
+    match arch::scan(input) {
+        Ok((true, true)) => Ok(Case::Mixed),
+        Ok((true, false)) => Ok(Case::Lower),
+        Ok((false, true)) => Ok(Case::Upper),
+        Ok((false, false)) => Ok(Case::DigitsOnly),
+        Err(offset) => Err(invalid_char(input, offset)),
+    }
+}
+
+#[inline]
+///Validates hex string in `buffer`, converting its letters to lower case in place.
+///
+///On error, characters before invalid one may be already converted.
+pub fn to_lower_in_place(buffer: &mut [u8]) -> Result<&mut str, DecodeErrorAt> {
+    match arch::change_case_in_place(false, buffer) {
+        Ok(()) => Ok(unsafe {
+            str::from_utf8_unchecked_mut(buffer)
+        }),
+        Err(offset) => Err(invalid_char(buffer, offset)),
+    }
+}
+
+#[inline]
+///Validates hex string in `buffer`, converting its letters to upper case in place.
+///
+///On error, characters before invalid one may be already converted.
+pub fn to_upper_in_place(buffer: &mut [u8]) -> Result<&mut str, DecodeErrorAt> {
+    match arch::change_case_in_place(true, buffer) {
+        Ok(()) => Ok(unsafe {
+            str::from_utf8_unchecked_mut(buffer)
+        }),
+        Err(offset) => Err(invalid_char(buffer, offset)),
+    }
+}
+
+#[inline]
+///Validates hex string `input`, writing it with letters in lower case into `out`.
+///
+///Output is truncated by its size, if necessary, and only written part of input is validated.
+pub fn to_lower(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeErrorAt> {
+    arch::change_case_copy(false, input, out).map_err(|offset| invalid_char(input, offset))
+}
+
+#[inline]
+///Validates hex string `input`, writing it with letters in upper case into `out`.
+///
+///Output is truncated by its size, if necessary, and only written part of input is validated.
+pub fn to_upper(input: &[u8], out: &mut [mem::MaybeUninit<u8>]) -> Result<usize, DecodeErrorAt> {
+    arch::change_case_copy(true, input, out).map_err(|offset| invalid_char(input, offset))
+}
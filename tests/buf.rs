@@ -0,0 +1,94 @@
+#![cfg(feature = "bytes")]
+
+use based16::{hex_lower_to_buf_mut, hex_upper_to_buf_mut, unhex_from_buf, DecodeError};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+const INPUT: &[u8] = b"\x00\x01\x7f\x80\xfe\xffbased16 for chads!";
+const INPUT_LOWER: &str = "00017f80feff6261736564313620666f7220636861647321";
+const INPUT_UPPER: &str = "00017F80FEFF6261736564313620666F7220636861647321";
+
+#[test]
+fn should_encode_into_buf_mut() {
+    let mut out = BytesMut::new();
+    let written = hex_lower_to_buf_mut(INPUT, &mut out);
+    assert_eq!(written, INPUT_LOWER.len());
+    assert_eq!(out, INPUT_LOWER.as_bytes());
+
+    let mut out = Vec::new();
+    hex_upper_to_buf_mut(INPUT, &mut out);
+    assert_eq!(out, INPUT_UPPER.as_bytes());
+
+    //Odd sized chunks force pairs to be split across chunks
+    for split in 0..=INPUT_LOWER.len() {
+        let mut first = vec![0u8; split];
+        let mut second = vec![0u8; INPUT_LOWER.len() - split];
+        let mut out = (&mut first[..]).chain_mut(&mut second[..]);
+        hex_lower_to_buf_mut(INPUT, &mut out);
+        assert_eq!(out.remaining_mut(), 0);
+
+        first.extend_from_slice(&second);
+        assert_eq!(first, INPUT_LOWER.as_bytes());
+    }
+}
+
+#[test]
+#[should_panic]
+fn should_panic_encoding_into_small_buf_mut() {
+    let mut out = [0u8; 3];
+    hex_lower_to_buf_mut(&[1, 2], &mut &mut out[..]);
+}
+
+#[test]
+fn should_decode_from_buf() {
+    let mut input = Bytes::from_static(INPUT_UPPER.as_bytes());
+    let mut out = BytesMut::new();
+    let written = unhex_from_buf(&mut input, &mut out).expect("to decode");
+    assert_eq!(written, INPUT.len());
+    assert_eq!(out, INPUT);
+    assert!(!input.has_remaining());
+
+    for split in 0..=INPUT_LOWER.len() {
+        let (first, second) = INPUT_LOWER.as_bytes().split_at(split);
+        let mut input = first.chain(second);
+        let mut out = Vec::new();
+        unhex_from_buf(&mut input, &mut out).expect("to decode");
+        assert_eq!(out, INPUT);
+    }
+
+    //Stops when output is full
+    let mut input = INPUT_LOWER.as_bytes();
+    let mut out = [0u8; 4];
+    let written = unhex_from_buf(&mut input, &mut &mut out[..]).expect("to decode");
+    assert_eq!(written, 4);
+    assert_eq!(out, INPUT[..4]);
+    assert_eq!(input, &INPUT_LOWER.as_bytes()[8..]);
+
+    //Odd character is left in input
+    let mut input = &b"abc"[..];
+    let mut out = Vec::new();
+    assert_eq!(unhex_from_buf(&mut input, &mut out).expect("to decode"), 1);
+    assert_eq!(out, [0xab]);
+    assert_eq!(input, b"c");
+}
+
+#[test]
+fn should_fail_decoding_invalid_buf() {
+    let mut input = (&b"abc"[..]).chain(&b"xef"[..]);
+    let mut out = Vec::new();
+    let error = unhex_from_buf(&mut input, &mut out).expect_err("to fail");
+    assert!(matches!(error, DecodeError::InvalidChar(b'x')));
+    assert_eq!(out, [0xab]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn should_not_consume_invalid_split_pair() {
+    for (first, second) in [(&b"abc"[..], &b"xef"[..]), (b"abx", b"cef")] {
+        let mut input = first.chain(second);
+        let mut out = Vec::new();
+        let error = unhex_from_buf(&mut input, &mut out).expect_err("to fail");
+        assert!(matches!(error, DecodeError::InvalidChar(b'x')));
+        assert_eq!(out, [0xab]);
+        assert_eq!(input.remaining(), 4);
+    }
+}
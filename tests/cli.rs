@@ -0,0 +1,177 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], input: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_based16")).args(args)
+                                                                .stdin(Stdio::piped())
+                                                                .stdout(Stdio::piped())
+                                                                .stderr(Stdio::piped())
+                                                                .spawn()
+                                                                .expect("to spawn");
+    let mut stdin = child.stdin.take().expect("stdin");
+    let input = input.to_vec();
+    //Write from separate thread to avoid deadlock on full stdout pipe.
+    //Write may fail if process exits early on error
+    let writer = std::thread::spawn(move || {
+        let _ = stdin.write_all(&input);
+    });
+    let output = child.wait_with_output().expect("to finish");
+    writer.join().expect("to join writer");
+    output
+}
+
+#[test]
+fn should_encode_stdin() {
+    let output = run(&[], b"\xde\xad\xbe\xef");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"deadbeef\n");
+
+    let output = run(&["-u", "--prefix", "--wrap", "4"], b"\xde\xad\xbe\xef\x01");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"0xDEAD\n0xBEEF\n0x01\n");
+
+    let output = run(&["--wrap=4"], b"\xde\xad");
+    assert_eq!(output.stdout, b"dead\n");
+
+    let output = run(&[], b"");
+    assert!(output.stdout.is_empty());
+
+    let input = (0..=255u8).cycle().take(200_000).collect::<Vec<_>>();
+    let output = run(&[], &input);
+    let mut expected = based16::Encoder::lower(&input).to_string();
+    expected.push('\n');
+    assert_eq!(output.stdout, expected.as_bytes());
+}
+
+#[test]
+fn should_decode_stdin() {
+    let output = run(&["-d"], b"DEADbeef");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"\xde\xad\xbe\xef");
+
+    //Lenient mode skips trailing odd character and lone carriage return, while whitespace only with -i
+    let output = run(&["-d", "-i"], b"de ad\tbe\r\nef\r0");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"\xde\xad\xbe\xef");
+
+    let output = run(&["-d"], b"dead be\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.stdout, b"\xde\xad");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "based16: <stdin>: Invalid character 0x20 at offset 4\n");
+
+    let output = run(&["-d", "-i"], b"de:ad\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.stdout, b"\xde");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "based16: <stdin>: Invalid character 0x3a at offset 2\n");
+
+    let output = run(&["-d", "-s", "-i", "-p"], b"0xDEAD\r\n  0xbeef\n0x01\n");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"\xde\xad\xbe\xef\x01");
+
+    let output = run(&["-d", "-s", "-i", "-p"], b"0a\n0b\n");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"\x0a\x0b");
+
+    let input = (0..=255u8).cycle().take(200_000).collect::<Vec<_>>();
+    let output = run(&["-d"], based16::Encoder::upper(&input).to_string().as_bytes());
+    assert_eq!(output.stdout, input);
+}
+
+#[test]
+fn should_decode_encoder_output_strictly() {
+    let output = run(&["-d", "-s"], b"deadbeef\n");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"\xde\xad\xbe\xef");
+
+    let output = run(&["-d", "-s"], b"dead\r\nbeef\r\n");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"\xde\xad\xbe\xef");
+
+    let input = (0..=255u8).cycle().take(200_000).collect::<Vec<_>>();
+    for args in [&["-w", "60"][..], &["-u", "-w", "7"], &[]] {
+        let encoded = run(args, &input);
+        assert!(encoded.status.success());
+
+        let output = run(&["-d", "-s"], &encoded.stdout);
+        assert!(output.status.success(), "stderr={}", String::from_utf8_lossy(&output.stderr));
+        assert_eq!(output.stdout, input);
+    }
+}
+
+#[test]
+fn should_fail_strict_decoding() {
+    let output = run(&["-d", "--strict"], b"dead beef\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.stdout, b"\xde\xad");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "based16: <stdin>: Invalid character 0x20 at offset 4\n");
+
+    //Line endings are accepted, but not lone carriage return
+    let output = run(&["-d", "-s"], b"dead\rbeef\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "based16: <stdin>: Invalid character 0x0d at offset 4\n");
+    let output = run(&["-d", "-s"], b"dead\r");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "based16: <stdin>: Invalid character 0x0d at offset 4\n");
+
+    let mut input = b"00".repeat(100_000);
+    input[150_001] = b'g';
+    let output = run(&["-d", "-s"], &input);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "based16: <stdin>: Invalid character 0x67 at offset 150001\n");
+
+    let output = run(&["-d", "-s", "-i"], b"abc\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.stdout, b"\xab");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "based16: <stdin>: Odd number of HEX characters, character at offset 2 has no pair\n");
+
+    let output = run(&["-d", "-s", "-p"], b"ab\n0xc\n0x\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.stdout, b"\xab");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "based16: <stdin>: Odd number of HEX characters, character at offset 5 has no pair\n");
+
+    let output = run(&["--unknown"], b"");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn should_read_files() {
+    let path = std::env::temp_dir().join(format!("based16-cli-test-{}.txt", std::process::id()));
+    std::fs::write(&path, b"chads").expect("to write file");
+    let path_str = path.to_str().expect("utf-8 path");
+
+    let output = run(&[path_str, "-", path_str], b"!");
+    std::fs::remove_file(&path).expect("to remove file");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"6368616473216368616473\n");
+
+    let output = run(&["/non/existing/file"], b"");
+    assert_eq!(output.status.code(), Some(1));
+
+    //Offset is reported within file containing invalid character
+    std::fs::write(&path, b"dead\n").expect("to write file");
+    let output = run(&["-d", "-s", path_str, "-"], b"be\nxx");
+    std::fs::remove_file(&path).expect("to remove file");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.stdout, b"\xde\xad\xbe");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "based16: <stdin>: Invalid character 0x78 at offset 3\n");
+
+    std::fs::write(&path, b"abc\n").expect("to write file");
+    let output = run(&["-d", "-s", "-", path_str], b"dd");
+    std::fs::remove_file(&path).expect("to remove file");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.stdout, b"\xdd\xab");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), format!("based16: {}: Odd number of HEX characters, character at offset 2 has no pair\n", path_str));
+
+    //Carriage return at the end of read buffer must be followed by line feed in the next one
+    let mut input = b"00".repeat(32 * 1024);
+    input[64 * 1024 - 2] = b'\n';
+    input[64 * 1024 - 1] = b'\r';
+    input.extend_from_slice(b"abcd\n");
+    std::fs::write(&path, &input).expect("to write file");
+    let output = run(&["-d", "-s", path_str], b"");
+    std::fs::remove_file(&path).expect("to remove file");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), format!("based16: {}: Invalid character 0x0d at offset 65535\n", path_str));
+}
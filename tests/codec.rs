@@ -0,0 +1,70 @@
+#![cfg(feature = "tokio-util")]
+
+use based16::{HexLinesCodec, HexLinesCodecError};
+use bytes::BytesMut;
+use tokio_util::codec::{Decoder, Encoder};
+
+#[test]
+fn should_decode_hex_lines() {
+    let mut codec = HexLinesCodec::default();
+    let mut buf = BytesMut::from(&b"deadBEEF\r\n\n0102"[..]);
+
+    assert_eq!(codec.decode(&mut buf).expect("to decode").expect("frame"), &[0xde, 0xad, 0xbe, 0xef][..]);
+    assert_eq!(codec.decode(&mut buf).expect("to decode").expect("frame"), &[][..]);
+    assert!(codec.decode(&mut buf).expect("to decode").is_none());
+
+    buf.extend_from_slice(b"03\nff");
+    assert_eq!(codec.decode(&mut buf).expect("to decode").expect("frame"), &[1, 2, 3][..]);
+    assert!(codec.decode(&mut buf).expect("to decode").is_none());
+    assert_eq!(codec.decode_eof(&mut buf).expect("to decode").expect("frame"), &[0xff][..]);
+    assert!(codec.decode_eof(&mut buf).expect("to decode").is_none());
+}
+
+#[test]
+fn should_report_hex_lines_errors() {
+    let mut codec = HexLinesCodec::lower().max_length(2);
+    assert_eq!(codec.get_max_length(), 2);
+    let mut buf = BytesMut::from(&b"0102\r\nabc\n0g\n010203"[..]);
+
+    assert_eq!(codec.decode(&mut buf).expect("to decode").expect("frame"), &[1, 2][..]);
+    match codec.decode(&mut buf) {
+        Err(HexLinesCodecError::OddLength { line: 2 }) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match codec.decode(&mut buf) {
+        Err(HexLinesCodecError::InvalidChar { line: 3, error: based16::DecodeError::InvalidChar(b'g') }) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match codec.decode(&mut buf) {
+        Err(HexLinesCodecError::MaxFrameLengthExceeded { line: 4 }) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    //Rest of too long line is discarded
+    assert!(codec.decode(&mut buf).expect("to decode").is_none());
+    buf.extend_from_slice(b"0405\nab\n");
+    assert_eq!(codec.decode(&mut buf).expect("to decode").expect("frame"), &[0xab][..]);
+
+    let error = codec.decode(&mut BytesMut::from(&b"01020\n"[..])).expect_err("to fail");
+    assert!(matches!(error, HexLinesCodecError::MaxFrameLengthExceeded { line: 6 }));
+    assert_eq!(error.to_string(), "Line 6: max frame length exceeded");
+}
+
+#[test]
+fn should_encode_hex_lines() {
+    let mut codec = HexLinesCodec::upper().max_length(4);
+    let mut buf = BytesMut::new();
+
+    codec.encode(&[0xde, 0xad, 0xbe, 0xef][..], &mut buf).expect("to encode");
+    codec.encode(bytes::Bytes::new(), &mut buf).expect("to encode");
+    codec.encode(vec![1, 2], &mut buf).expect("to encode");
+    assert_eq!(buf, &b"DEADBEEF\n\n0102\n"[..]);
+
+    let error = codec.encode(&[0u8; 5][..], &mut buf).expect_err("to fail");
+    assert!(matches!(error, HexLinesCodecError::MaxFrameLengthExceeded { line: 4 }));
+
+    let mut lower = HexLinesCodec::lower();
+    let mut frames = BytesMut::new();
+    lower.encode(&b"chads"[..], &mut frames).expect("to encode");
+    assert_eq!(frames, &b"6368616473\n"[..]);
+    assert_eq!(lower.decode(&mut frames).expect("to decode").expect("frame"), &b"chads"[..]);
+}
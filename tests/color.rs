@@ -0,0 +1,48 @@
+use based16::color::{ColorError, Format, Rgba};
+use based16::{DecodeError, DecodeErrorAt};
+
+const ORANGE: Rgba = Rgba::from_literal("#ff8800");
+
+#[test]
+fn should_parse_color() {
+    assert_eq!(ORANGE, Rgba::rgb(0xff, 0x88, 0x00));
+    assert_eq!(Rgba::parse("f80"), Ok(ORANGE));
+    assert_eq!(Rgba::parse("#F80"), Ok(ORANGE));
+    assert_eq!("#f808".parse::<Rgba>(), Ok(Rgba::new(0xff, 0x88, 0x00, 0x88)));
+    assert_eq!(Rgba::parse("FF8800"), Ok(ORANGE));
+    assert_eq!(Rgba::parse("#12345678"), Ok(Rgba::new(0x12, 0x34, 0x56, 0x78)));
+
+    assert_eq!(Rgba::parse(""), Err(ColorError::InvalidLength(0)));
+    assert_eq!(Rgba::parse("#"), Err(ColorError::InvalidLength(0)));
+    assert_eq!(Rgba::parse("#12345"), Err(ColorError::InvalidLength(5)));
+    assert_eq!(Rgba::parse("##123"), Err(ColorError::InvalidChar(DecodeErrorAt {
+        offset: 1,
+        error: DecodeError::InvalidChar(b'#'),
+    })));
+    assert_eq!(Rgba::parse("#12g"), Err(ColorError::InvalidChar(DecodeErrorAt {
+        offset: 3,
+        error: DecodeError::InvalidChar(b'g'),
+    })));
+    assert_eq!(Rgba::parse("#12345z"), Err(ColorError::InvalidChar(DecodeErrorAt {
+        offset: 6,
+        error: DecodeError::InvalidChar(b'z'),
+    })));
+    assert_eq!(ColorError::InvalidLength(5).to_string(), "Invalid colour length 5");
+}
+
+#[test]
+fn should_format_color() {
+    const TEXT: &str = ORANGE.format(Format::new().upper()).as_str();
+    assert_eq!(TEXT, "#FF8800");
+
+    assert_eq!(ORANGE.to_string(), "#ff8800");
+    assert_eq!(&*ORANGE.format(Format::new().shorthand()), "#f80");
+    assert_eq!(ORANGE.format(Format::new().without_hash().always_alpha()).as_str(), "ff8800ff");
+    assert_eq!(ORANGE.format(Format::default().shorthand().always_alpha().upper()).as_str(), "#F80F");
+    assert_eq!(format!("{:?}", ORANGE.format(Format::new())), "\"#ff8800\"");
+
+    let color = Rgba::new(0x12, 0x33, 0x44, 0x80);
+    assert_eq!(color.to_string(), "#12334480");
+    assert_eq!(color.format(Format::new().shorthand()).as_str(), "#12334480");
+    assert_eq!(Rgba::new(0x11, 0x22, 0x33, 0x44).format(Format::new().shorthand()).as_str(), "#1234");
+}
@@ -0,0 +1,8 @@
+use core::mem;
+
+///Returns first `len` bytes of `buffer`, which must be initialized
+pub fn as_bytes(buffer: &[mem::MaybeUninit<u8>], len: usize) -> &[u8] {
+    unsafe {
+        core::slice::from_raw_parts(buffer.as_ptr() as *const u8, len)
+    }
+}
@@ -0,0 +1,65 @@
+use based16::escape::{self, Encoder, InvalidEscape, Style};
+
+use core::mem;
+
+mod common;
+use common::as_bytes;
+
+const INPUT: &[u8] = b"GET \"/\\\"\r\n\t\x00\x7f\xffab\x01ag";
+
+#[test]
+fn should_escape_bytes() {
+    assert_eq!(Encoder::c(INPUT).to_string(), "GET \\\"/\\\\\\\"\\r\\n\\t\\x00\\x7f\\xff\\x61\\x62\\x01\\x61g");
+    assert_eq!(Encoder::rust(INPUT).to_string(), "GET \\\"/\\\\\\\"\\r\\n\\t\\0\\x7f\\xffab\\x01ag");
+    assert_eq!(Encoder::python(INPUT).to_string(), "GET \\\"/\\\\\\\"\\r\\n\\t\\x00\\x7f\\xffab\\x01ag");
+
+    assert_eq!(format!("{:?}", Encoder::c(b"a\x00")), "\"a\\x00\"");
+    assert_eq!(format!("{:?}", Encoder::rust(b"a\x00")), "b\"a\\0\"");
+    assert_eq!(format!("{:?}", Encoder::new(b"a\x00", Style::Python)), "b\"a\\x00\"");
+
+    let mut out = [mem::MaybeUninit::uninit(); 128];
+    assert!(escape::required_encode_len(INPUT.len()) <= out.len());
+    let len = escape::encode(INPUT, Style::Rust, &mut out);
+    assert_eq!(as_bytes(&out, len), Encoder::rust(INPUT).to_string().as_bytes());
+
+    //Escape sequence is never split
+    let len = escape::encode(b"a\xff", Style::Python, &mut out[..4]);
+    assert_eq!(as_bytes(&out, len), b"a");
+
+    //State of C hex escape is carried over between formatting chunks
+    let mut input = vec![b'a'; 1000];
+    input[0] = 0;
+    assert_eq!(Encoder::c(&input).to_string(), "\\x00".to_owned() + &"\\x61".repeat(999));
+}
+
+#[test]
+fn should_unescape_bytes() {
+    let mut out = [mem::MaybeUninit::uninit(); 64];
+
+    for style in [Style::C, Style::Rust, Style::Python] {
+        let escaped = Encoder::new(INPUT, style).to_string();
+        let len = escape::decode(escaped.as_bytes(), style, &mut out).expect("to decode");
+        assert_eq!(as_bytes(&out, len), INPUT, "style={:?}", style);
+
+        let len = escape::decode(escaped.as_bytes(), style, &mut out[..4]).expect("to decode");
+        assert_eq!(as_bytes(&out, len), b"GET ");
+    }
+
+    let len = escape::decode(b"\\101\\0\\7a\\x0041\\a\\?'\\'", Style::C, &mut out).expect("to decode");
+    assert_eq!(as_bytes(&out, len), b"A\x00\x07aA\x07?''");
+    let len = escape::decode(b"\\101\\x41\\\nb\\v", Style::Python, &mut out).expect("to decode");
+    assert_eq!(as_bytes(&out, len), b"AAb\x0b");
+    let len = escape::decode(b"a\\\n   b\\0\\x41", Style::Rust, &mut out).expect("to decode");
+    assert_eq!(as_bytes(&out, len), b"ab\x00A");
+
+    assert_eq!(escape::decode(b"ab\\", Style::C, &mut out), Err(InvalidEscape { offset: 2 }));
+    assert_eq!(escape::decode(b"a\\400", Style::C, &mut out), Err(InvalidEscape { offset: 1 }));
+    assert_eq!(escape::decode(b"a\\x100", Style::C, &mut out), Err(InvalidEscape { offset: 1 }));
+    assert_eq!(escape::decode(b"\\xg", Style::C, &mut out), Err(InvalidEscape { offset: 0 }));
+    assert_eq!(escape::decode(b"\\101", Style::Rust, &mut out), Err(InvalidEscape { offset: 0 }));
+    assert_eq!(escape::decode(b"\\x4", Style::Rust, &mut out), Err(InvalidEscape { offset: 0 }));
+    assert_eq!(escape::decode(b"\\x4g", Style::Python, &mut out), Err(InvalidEscape { offset: 0 }));
+    assert_eq!(escape::decode(b"\\a", Style::Rust, &mut out), Err(InvalidEscape { offset: 0 }));
+    assert_eq!(escape::decode(b"\\?", Style::Python, &mut out), Err(InvalidEscape { offset: 0 }));
+    assert_eq!(InvalidEscape { offset: 1 }.to_string(), "Invalid escape sequence at offset 1");
+}
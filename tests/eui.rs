@@ -0,0 +1,62 @@
+use based16::eui::{Eui, Eui48, Eui64, EuiError, Notation};
+use based16::{DecodeError, DecodeErrorAt};
+
+const MAC: Eui48 = Eui48::from_literal("00:1A:2b:3c:4d:5e");
+const EUI64: Eui64 = Eui64::from_literal("0012.34ff.fe56.789a");
+
+#[test]
+fn should_format_eui() {
+    assert_eq!(MAC.lower(Notation::Colon).to_string(), "00:1a:2b:3c:4d:5e");
+    assert_eq!(MAC.upper(Notation::Hyphen).to_string(), "00-1A-2B-3C-4D-5E");
+    assert_eq!(MAC.lower(Notation::Dot).to_string(), "001a.2b3c.4d5e");
+    assert_eq!(MAC.upper(Notation::Bare).to_string(), "001A2B3C4D5E");
+    assert_eq!(MAC.to_string(), "00:1a:2b:3c:4d:5e");
+    assert_eq!(format!("{:?}", MAC), "Eui(00:1a:2b:3c:4d:5e)");
+
+    assert_eq!(EUI64.lower(Notation::Colon).to_string(), "00:12:34:ff:fe:56:78:9a");
+    assert_eq!(EUI64.upper(Notation::Dot).to_string(), "0012.34FF.FE56.789A");
+
+    for notation in [Notation::Colon, Notation::Hyphen, Notation::Dot, Notation::Bare] {
+        assert_eq!(notation.text_len(6), MAC.lower(notation).to_string().len());
+        assert_eq!(notation.text_len(8), EUI64.lower(notation).to_string().len());
+        assert_eq!(notation.text_len(0), 0);
+    }
+}
+
+#[test]
+fn should_parse_eui() {
+    let expected = Eui48::new([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]);
+    assert_eq!(MAC, expected);
+    for text in ["00:1a:2b:3c:4d:5e", "00-1A-2B-3C-4D-5E", "001a.2b3c.4d5e", "001A2b3c4d5E"] {
+        assert_eq!(text.parse::<Eui48>(), Ok(expected));
+    }
+    assert_eq!(Eui64::parse("00-12-34-FF-FE-56-78-9A"), Ok(EUI64));
+    assert_eq!(Eui::<8>::from([0, 0x12, 0x34, 0xff, 0xfe, 0x56, 0x78, 0x9a]), EUI64);
+
+    assert_eq!(Eui48::parse(""), Err(EuiError::InvalidLength(0)));
+    assert_eq!(Eui48::parse("00:1a:2b:3c:4d:5e:"), Err(EuiError::InvalidLength(18)));
+    assert_eq!(Eui48::parse("00:1a:2b:3c:4d:5e".trim_end_matches('e')), Err(EuiError::InvalidLength(16)));
+    assert_eq!(Eui48::parse("00:1a-2b:3c:4d:5e"), Err(EuiError::MixedSeparators(5)));
+    assert_eq!(Eui48::parse("001a.2b3c:4d5e"), Err(EuiError::MixedSeparators(9)));
+    assert_eq!(Eui48::parse("00_1a_2b_3c_4d_5e"), Err(EuiError::InvalidSeparator(2)));
+    //Dot notation has different length
+    assert_eq!(Eui48::parse("00.1a:2b:3c:4d:5e"), Err(EuiError::InvalidSeparator(2)));
+    assert_eq!(Eui48::parse("00:1a:2b:3c:4d_5e"), Err(EuiError::InvalidSeparator(14)));
+    assert_eq!(Eui48::parse("001a.2b3c_4d5e"), Err(EuiError::InvalidSeparator(9)));
+    assert_eq!(Eui48::parse("00:1a:2b:3g:4d:5e"), Err(EuiError::InvalidChar(DecodeErrorAt {
+        offset: 10,
+        error: DecodeError::InvalidChar(b'g'),
+    })));
+    assert_eq!(Eui48::parse("001a2b3c4d:e"), Err(EuiError::InvalidChar(DecodeErrorAt {
+        offset: 10,
+        error: DecodeError::InvalidChar(b':'),
+    })));
+    assert_eq!(EuiError::MixedSeparators(5).to_string(), "Mixed separators at offset 5");
+    assert_eq!(EuiError::InvalidSeparator(2).to_string(), "Expected separator at offset 2");
+}
+
+#[test]
+#[should_panic]
+fn should_panic_on_invalid_literal() {
+    Eui48::from_literal("00:1a:2b:3c:4d:5z");
+}
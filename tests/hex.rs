@@ -1,11 +1,19 @@
-use based16::{Encoder, Decoder, CharPair};
+use based16::{Encoder, Decoder, RevEncoder, RevDecoder, CharPair, Fmt};
 use based16::{hex_lower, hex_upper, unhex};
+use based16::{hex_lower_rev, hex_upper_rev, unhex_rev, unhex_in_place};
+use based16::{hex_lower_in_place, hex_upper_in_place};
 #[cfg(feature = "alloc")]
-use based16::{hex_lower_to_vec, hex_upper_to_vec, unhex_to_vec};
+use based16::{hex_lower_to_vec, hex_upper_to_vec, unhex_to_vec, unhex_vec_in_place};
+#[cfg(feature = "alloc")]
+use based16::{hex_lower_vec_in_place, hex_upper_vec_in_place};
 use based16::{const_hex_lower, const_hex_upper};
+use based16::{hex_lower_to_fmt, hex_upper_to_fmt};
 
 use core::mem;
 
+mod common;
+use common::as_bytes;
+
 const ALL: [u8; 256] = [
     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
     0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F,
@@ -213,8 +221,7 @@ fn should_convert_min_binary() {
     let expected_output = "99AABBCCDDEEFF";
     let mut encoded_hex = [mem::MaybeUninit::uninit(); 14];
 
-    for idx in 0..INPUT.len() {
-        let input = INPUT[idx];
+    for input in INPUT {
         let expected_output = &expected_output[..input.len() * 2];
 
         let encoded_len = hex_upper(input, &mut encoded_hex);
@@ -227,3 +234,640 @@ fn should_convert_min_binary() {
         assert_eq!(expected_output, encoded_hex);
     }
 }
+
+#[test]
+fn should_encode_to_fmt() {
+    let mut output = String::new();
+    hex_upper_to_fmt(&ALL, &mut output).expect("to write");
+    assert_eq!(ALL_UPPER, output);
+
+    output.clear();
+    hex_lower_to_fmt(&ALL, &mut output).expect("to write");
+    assert_eq!(ALL_LOWER, output);
+
+    output.clear();
+    hex_lower_to_fmt(&[], &mut output).expect("to write");
+    assert!(output.is_empty());
+
+    let mut big = [0u8; 1000];
+    for (idx, byt) in big.iter_mut().enumerate() {
+        *byt = idx as u8;
+    }
+    hex_lower_to_fmt(&big, &mut output).expect("to write");
+    assert_eq!(output, Encoder::lower(&big).map(|pair| pair.as_str().to_owned()).collect::<String>());
+}
+
+#[test]
+fn should_format_encoder_with_flags() {
+    const INPUT: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+    let encoder = Encoder::lower(&INPUT);
+
+    assert_eq!(format!("{:#}", encoder), "0xdeadbeef");
+    assert_eq!(format!("{:#?}", encoder), "\"deadbeef\"");
+    assert_eq!(format!("{:.2}", encoder), "dead...");
+    assert_eq!(format!("{:.4}", encoder), "deadbeef");
+    assert_eq!(format!("{:.0}", encoder), "...");
+    assert_eq!(format!("{:#.1}", Encoder::upper(&INPUT)), "0xDE...");
+    assert_eq!(format!("{:?}", Encoder::upper(&INPUT)), "\"DEADBEEF\"");
+
+    assert_eq!(format!("{:12}", encoder), "deadbeef    ");
+    assert_eq!(format!("{:>12}", encoder), "    deadbeef");
+    assert_eq!(format!("{:*^12}", encoder), "**deadbeef**");
+    assert_eq!(format!("{:_>#12}", encoder), "__0xdeadbeef");
+    assert_eq!(format!("{:4}", encoder), "deadbeef");
+    assert_eq!(format!("{:>8.1}", encoder), "   de...");
+    assert_eq!(format!("{:>12?}", encoder), "  \"deadbeef\"");
+}
+
+#[test]
+fn should_format_bytes_with_fmt_wrapper() {
+    const INPUT: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+
+    assert_eq!(format!("{:x}", Fmt(&ALL)), ALL_LOWER);
+    assert_eq!(format!("{:X}", Fmt(&ALL)), ALL_UPPER);
+    assert_eq!(format!("{}", Fmt(ALL.to_vec())), ALL_LOWER);
+
+    assert_eq!(format!("{:x}", Fmt(INPUT)), "deadbeef");
+    assert_eq!(format!("{:#X}", Fmt(INPUT)), "0xDEADBEEF");
+    assert_eq!(format!("{:?}", Fmt(&INPUT[..])), "\"deadbeef\"");
+    assert_eq!(format!("{:#?}", Fmt(&INPUT[..])), "\"deadbeef\"");
+    assert_eq!(format!("{:.3X}", Fmt(INPUT)), "DEADBE...");
+    assert_eq!(format!("{:>#12x}", Fmt(INPUT)), "  0xdeadbeef");
+    assert_eq!(format!("{:-<10X}", Fmt(INPUT)), "DEADBEEF--");
+    assert_eq!(format!("{:^10x}", Fmt([0u8; 0])), "          ");
+}
+
+#[test]
+fn should_convert_hex_and_back_reversed() {
+    let mut reversed = ALL;
+    reversed.reverse();
+    let reversed_upper = Encoder::upper(&reversed).to_string();
+    let reversed_lower = Encoder::lower(&reversed).to_string();
+
+    assert_eq!(RevEncoder::upper(&ALL).to_string(), reversed_upper);
+    assert_eq!(RevEncoder::lower(&ALL).to_string(), reversed_lower);
+    assert_eq!(format!("{:#.2?}", RevEncoder::lower(&ALL)), "\"fffe...\"");
+    assert_eq!(format!("{:#.2}", RevEncoder::lower(&ALL)), "0xfffe...");
+    assert_eq!(RevEncoder::lower(&ALL).map(|pair| pair.as_str().to_owned()).collect::<String>(), reversed_lower);
+    assert_eq!(RevEncoder::lower(&ALL).rev().map(|pair| pair.as_str().to_owned()).collect::<String>(), ALL_LOWER);
+
+    let mut encoded_hex = [mem::MaybeUninit::uninit(); ALL.len() * 2];
+    for len in 0..=ALL.len() {
+        let input = &ALL[..len];
+        let expected = Encoder::upper(&reversed[ALL.len() - len..]).to_string();
+        let encoded_len = hex_upper_rev(input, &mut encoded_hex);
+        assert_eq!(encoded_len, len * 2);
+        let encoded = unsafe {
+            core::slice::from_raw_parts(encoded_hex.as_ptr() as *const u8, encoded_len)
+        };
+        assert_eq!(expected.as_bytes(), encoded);
+
+        //Truncated output starts from the last byte
+        let encoded_len = hex_lower_rev(&ALL, &mut encoded_hex[..len * 2]);
+        assert_eq!(encoded_len, len * 2);
+        let encoded = unsafe {
+            core::slice::from_raw_parts(encoded_hex.as_ptr() as *const u8, encoded_len)
+        };
+        assert_eq!(&reversed_lower.as_bytes()[..len * 2], encoded);
+    }
+
+    let mut decoded_hex = [mem::MaybeUninit::uninit(); ALL.len()];
+    for len in 0..=ALL.len() {
+        let decode_len = unhex_rev(ALL_UPPER.as_bytes(), &mut decoded_hex[..len]).expect("Success");
+        assert_eq!(decode_len, len);
+        let decoded = unsafe {
+            core::slice::from_raw_parts(decoded_hex.as_ptr() as *const u8, decode_len)
+        };
+        assert_eq!(&reversed[..len], decoded);
+
+        let decode_len = unhex_rev(&ALL_LOWER.as_bytes()[..len * 2], &mut decoded_hex).expect("Success");
+        assert_eq!(decode_len, len);
+        let decoded = unsafe {
+            core::slice::from_raw_parts(decoded_hex.as_ptr() as *const u8, decode_len)
+        };
+        assert_eq!(&reversed[ALL.len() - len..], decoded);
+    }
+
+    let mut invalid = ALL_LOWER.as_bytes().to_vec();
+    invalid[3] = b'g';
+    let error = unhex_rev(&invalid, &mut decoded_hex).expect_err("Should fail");
+    assert!(matches!(error, based16::DecodeError::InvalidChar(b'g')));
+
+    let decoder = RevDecoder::new(ALL_UPPER).expect("To have valid input");
+    assert_eq!(decoder.len(), ALL.len());
+    let decoded = decoder.collect::<Result<Vec<u8>, _>>().expect("to decode");
+    assert_eq!(decoded, reversed);
+    let decoded = RevDecoder::new(ALL_LOWER).expect("To have valid input").rev().collect::<Result<Vec<u8>, _>>().expect("to decode");
+    assert_eq!(decoded, ALL);
+    assert!(RevDecoder::new("abc").is_none());
+}
+
+#[test]
+fn should_iterate_encoder_from_both_ends() {
+    let mut encoder = Encoder::upper(&ALL);
+    assert_eq!(encoder.len(), ALL.len());
+    assert_eq!(encoder.next_back().expect("last byte").as_str(), "FF");
+    assert_eq!(encoder.next().expect("first byte").as_str(), "00");
+    assert_eq!(encoder.nth(9).expect("10th byte").as_str(), "0A");
+    assert_eq!(encoder.nth_back(4).expect("5th byte from the end").as_str(), "FA");
+    assert_eq!(encoder.len(), ALL.len() - 2 - 10 - 5);
+    assert_eq!(encoder.as_slice(), &ALL[11..250]);
+
+    let (left, right) = encoder.split_at(100);
+    assert_eq!(left.as_slice(), &ALL[11..111]);
+    assert_eq!(right.as_slice(), &ALL[111..250]);
+    assert_eq!(format!("{}{}", left, right), format!("{}", encoder));
+
+    assert!(encoder.nth(1000).is_none());
+    assert!(encoder.next().is_none());
+    assert!(encoder.next_back().is_none());
+    assert!(Encoder::lower(&ALL).nth_back(ALL.len()).is_none());
+    assert_eq!(Encoder::lower(&ALL).last().expect("last byte").as_str(), "ff");
+
+    let pairs = Encoder::lower(&ALL).rev().map(|pair| pair.as_str().to_owned()).collect::<Vec<_>>();
+    let expected = ALL_LOWER.as_bytes().chunks(2).rev().map(|pair| core::str::from_utf8(pair).expect("utf-8").to_owned()).collect::<Vec<_>>();
+    assert_eq!(pairs, expected);
+}
+
+#[test]
+fn should_iterate_decoder_from_both_ends() {
+    let mut decoder = Decoder::new(ALL_LOWER).expect("To have valid input");
+    assert_eq!(decoder.len(), ALL.len());
+    assert_eq!(decoder.size_hint(), (ALL.len(), Some(ALL.len())));
+    assert_eq!(decoder.next_back().expect("last byte").expect("to decode"), 0xff);
+    assert_eq!(decoder.next().expect("first byte").expect("to decode"), 0x00);
+    assert_eq!(decoder.nth(9).expect("10th byte").expect("to decode"), 0x0a);
+    assert_eq!(decoder.nth_back(4).expect("5th byte from the end").expect("to decode"), 0xfa);
+    assert_eq!(decoder.len(), ALL.len() - 2 - 10 - 5);
+    assert_eq!(decoder.as_slice(), &ALL_LOWER.as_bytes()[22..500]);
+
+    let (left, right) = decoder.split_at(100);
+    assert_eq!(left.len(), 100);
+    assert_eq!(right.len(), 139);
+    let left = left.collect::<Result<Vec<_>, _>>().expect("to decode");
+    let right = right.rev().collect::<Result<Vec<_>, _>>().expect("to decode");
+    assert_eq!(left, &ALL[11..111]);
+    assert!(right.iter().copied().eq(ALL[111..250].iter().copied().rev()));
+
+    assert!(decoder.nth(1000).is_none());
+    assert!(decoder.next().is_none());
+    assert!(decoder.next_back().is_none());
+    assert_eq!(decoder.len(), 0);
+    assert!(Decoder::new(ALL_LOWER).expect("valid").nth_back(ALL.len()).is_none());
+    assert_eq!(Decoder::new(ALL_LOWER).expect("valid").count(), ALL.len());
+}
+
+#[test]
+fn should_decode_in_place() {
+    for len in 0..=ALL.len() {
+        let mut buffer = ALL_UPPER.as_bytes()[..len * 2].to_vec();
+        let decoded = unhex_in_place(&mut buffer).expect("Success");
+        assert_eq!(decoded, &ALL[..len]);
+
+        let mut buffer = ALL_LOWER.as_bytes()[..len * 2].to_vec();
+        buffer.push(b'f');
+        let decoded = unhex_in_place(&mut buffer).expect("Success");
+        assert_eq!(decoded, &ALL[..len]);
+    }
+
+    for idx in 0..64 {
+        let mut buffer = ALL_LOWER.as_bytes()[..64].to_vec();
+        buffer[idx] = b'z';
+        let error = unhex_in_place(&mut buffer).expect_err("Should fail");
+        assert!(matches!(error, based16::DecodeError::InvalidChar(b'z')));
+    }
+
+    #[cfg(feature = "alloc")]
+    {
+        let mut buffer = ALL_LOWER.as_bytes().to_vec();
+        let decoded_len = unhex_vec_in_place(&mut buffer).expect("Success");
+        assert_eq!(decoded_len, ALL.len());
+        assert_eq!(buffer, ALL);
+
+        let mut buffer = b"abzz".to_vec();
+        unhex_vec_in_place(&mut buffer).expect_err("Should fail");
+        assert_eq!(buffer.len(), 4);
+    }
+}
+
+#[test]
+fn should_encode_in_place() {
+    for len in 0..=ALL.len() {
+        let mut buffer = [0u8; ALL.len() * 2 + 1];
+        buffer[..len].copy_from_slice(&ALL[..len]);
+        let encoded = hex_upper_in_place(&mut buffer, len);
+        assert_eq!(encoded, &ALL_UPPER[..len * 2]);
+
+        buffer[..len].copy_from_slice(&ALL[..len]);
+        let encoded = hex_lower_in_place(&mut buffer[..len * 2], len);
+        assert_eq!(encoded, &ALL_LOWER[..len * 2]);
+    }
+
+    #[cfg(feature = "alloc")]
+    {
+        let mut buffer = ALL.to_vec();
+        let encoded_len = hex_lower_vec_in_place(&mut buffer);
+        assert_eq!(encoded_len, ALL_LOWER.len());
+        assert_eq!(buffer, ALL_LOWER.as_bytes());
+
+        let mut buffer = ALL.to_vec();
+        hex_upper_vec_in_place(&mut buffer);
+        assert_eq!(buffer, ALL_UPPER.as_bytes());
+    }
+}
+
+#[test]
+#[should_panic]
+fn should_panic_encoding_in_place_into_small_buffer() {
+    let mut buffer = [0u8; 7];
+    hex_lower_in_place(&mut buffer, 4);
+}
+
+#[test]
+fn should_convert_with_alphabet() {
+    use based16::{Alphabet, AlphabetError, hex_with_alphabet, unhex_with_alphabet};
+
+    const REVERSED: Alphabet = match Alphabet::new(b"fedcba9876543210") {
+        Ok(alphabet) => alphabet,
+        Err(_) => panic!("Invalid alphabet"),
+    };
+    //Contiguous ranges, but not hex
+    const SHIFTED: Alphabet = match Alphabet::new(b"ABCDEFGHIJklmnop") {
+        Ok(alphabet) => alphabet,
+        Err(_) => panic!("Invalid alphabet"),
+    };
+
+    assert_eq!(Alphabet::new(b"0123456789abcdea"), Err(AlphabetError::Duplicate(b'a')));
+    assert_eq!(Alphabet::new(b"0123456789abcde\xff"), Err(AlphabetError::NonAscii(0xff)));
+    assert_eq!(REVERSED.nibble(b'f'), Some(0));
+    assert_eq!(REVERSED.nibble(b'F'), None);
+    assert_eq!(format!("{:?}", REVERSED), "Alphabet(\"fedcba9876543210\")");
+    //Exact case decoding differs from predefined alphabets
+    assert_ne!(Alphabet::new(b"0123456789abcdef"), Ok(Alphabet::LOWER));
+
+    for (alphabet, expected) in [(&Alphabet::LOWER, ALL_LOWER.to_owned()), (&Alphabet::UPPER, ALL_UPPER.to_owned())] {
+        assert_eq!(Encoder::with_alphabet(&ALL, alphabet).to_string(), expected);
+    }
+
+    for alphabet in [&REVERSED, &SHIFTED, &Alphabet::LOWER] {
+        let expected = ALL_LOWER.bytes().map(|ch| alphabet.symbols()[(ch as char).to_digit(16).expect("hex") as usize]).collect::<Vec<_>>();
+        assert_eq!(Encoder::with_alphabet(&ALL, alphabet).to_string().as_bytes(), expected.as_slice());
+
+        let mut encoded = [mem::MaybeUninit::uninit(); ALL.len() * 2];
+        let mut decoded = [mem::MaybeUninit::uninit(); ALL.len()];
+        for len in 0..=ALL.len() {
+            let encoded_len = hex_with_alphabet(alphabet, &ALL[..len], &mut encoded);
+            assert_eq!(encoded_len, len * 2);
+            let encoded = unsafe {
+                core::slice::from_raw_parts(encoded.as_ptr() as *const u8, encoded_len)
+            };
+            assert_eq!(encoded, &expected[..len * 2]);
+
+            let decoded_len = unhex_with_alphabet(alphabet, encoded, &mut decoded).expect("to decode");
+            assert_eq!(decoded_len, len);
+            let decoded = unsafe {
+                core::slice::from_raw_parts(decoded.as_ptr() as *const u8, decoded_len)
+            };
+            assert_eq!(decoded, &ALL[..len]);
+        }
+    }
+
+    let mut decoded = [mem::MaybeUninit::uninit(); 2];
+    assert_eq!(unhex_with_alphabet(&Alphabet::UPPER, b"abCD", &mut decoded).expect("to decode"), 2);
+    assert_eq!(unhex_with_alphabet(&REVERSED, b"feF0", &mut decoded).expect_err("to fail"), based16::DecodeError::InvalidChar(b'F'));
+}
+
+#[test]
+fn should_decode_odd_length_with_padding() {
+    use based16::{unhex_pad_left, unhex_pad_right, unhex_nibble, required_padded_decode_len, DecodeError};
+
+    assert_eq!(unhex_nibble(b'F'), Ok(15));
+    assert_eq!(unhex_nibble(b'g'), Err(DecodeError::InvalidChar(b'g')));
+    assert_eq!(required_padded_decode_len(0), 0);
+    assert_eq!(required_padded_decode_len(3), 2);
+    assert_eq!(required_padded_decode_len(usize::MAX), usize::MAX / 2 + 1);
+
+    let mut out = [mem::MaybeUninit::uninit(); 32];
+    let input = "abc0123456789ABCDEF0123456789abcdef";
+    let len = unhex_pad_left(input.as_bytes(), &mut out).expect("to decode");
+    assert_eq!(len, required_padded_decode_len(input.len()));
+    assert_eq!(as_bytes(&out, len), [0x0a, 0xbc, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+    let len = unhex_pad_right(input.as_bytes(), &mut out).expect("to decode");
+    assert_eq!(len, required_padded_decode_len(input.len()));
+    assert_eq!(as_bytes(&out, len), [0xab, 0xc0, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0]);
+
+    //Even length is decoded as usual
+    let len = unhex_pad_left(b"abcd", &mut out).expect("to decode");
+    assert_eq!(as_bytes(&out, len), [0xab, 0xcd]);
+    let len = unhex_pad_right(b"abcd", &mut out).expect("to decode");
+    assert_eq!(as_bytes(&out, len), [0xab, 0xcd]);
+
+    //Truncation by output size
+    assert_eq!(unhex_pad_left(b"abc", &mut out[..0]), Ok(0));
+    let len = unhex_pad_left(b"abc", &mut out[..1]).expect("to decode");
+    assert_eq!(as_bytes(&out, len), [0x0a]);
+    let len = unhex_pad_right(b"abc", &mut out[..1]).expect("to decode");
+    assert_eq!(as_bytes(&out, len), [0xab]);
+    let len = unhex_pad_right(b"a", &mut out).expect("to decode");
+    assert_eq!(as_bytes(&out, len), [0xa0]);
+
+    assert_eq!(unhex_pad_left(b"gab", &mut out), Err(DecodeError::InvalidChar(b'g')));
+    assert_eq!(unhex_pad_left(b"agb", &mut out), Err(DecodeError::InvalidChar(b'g')));
+    assert_eq!(unhex_pad_right(b"abg", &mut out), Err(DecodeError::InvalidChar(b'g')));
+}
+
+#[test]
+fn should_iterate_nibbles() {
+    use based16::Nibbles;
+
+    let nibbles = Nibbles::new(&[0x1a, 0x2b, 0x3c]);
+    assert_eq!(nibbles.len(), 6);
+    assert_eq!(nibbles.collect::<Vec<_>>(), [0x1, 0xa, 0x2, 0xb, 0x3, 0xc]);
+    assert_eq!(nibbles.rev().collect::<Vec<_>>(), [0xc, 0x3, 0xb, 0x2, 0xa, 0x1]);
+    assert_eq!(nibbles.to_string(), "1a2b3c");
+    assert_eq!(format!("{:X}", nibbles), "1A2B3C");
+    assert_eq!(format!("{:?}", nibbles), "\"1a2b3c\"");
+
+    //Same flags as Encoder, with precision counting nibbles
+    let odd = nibbles.split_at(1).1;
+    assert_eq!(format!("{:#}", odd), "0xa2b3c");
+    assert_eq!(format!("{:#X}", odd), "0xA2B3C");
+    assert_eq!(format!("{:#?}", odd), "\"a2b3c\"");
+    assert_eq!(format!("{:.3}", odd), "a2b...");
+    assert_eq!(format!("{:.5}", odd), "a2b3c");
+    assert_eq!(format!("{:8}", odd), "a2b3c   ");
+    assert_eq!(format!("{:*^9}", odd), "**a2b3c**");
+    assert_eq!(format!("{:_>#9X}", odd), "__0xA2B3C");
+    assert_eq!(format!("{:>8.1}", odd), "    a...");
+    assert_eq!(format!("{:>9?}", odd), "  \"a2b3c\"");
+    assert_eq!(format!("{:#}", based16::Encoder::lower(&[0x1a, 0x2b, 0x3c])), format!("{:#}", nibbles));
+    assert_eq!(nibbles.get(1), Some(0xa));
+    assert_eq!(nibbles.get(6), None);
+    assert_eq!(nibbles.last(), Some(0xc));
+    assert_eq!(nibbles.count(), 6);
+
+    let (left, right) = nibbles.split_at(3);
+    assert_eq!(left.to_string(), "1a2");
+    assert_eq!(right.to_string(), "b3c");
+    assert_eq!(right.get(0), Some(0xb));
+    let (left, right) = right.split_at(1);
+    assert_eq!(left.collect::<Vec<_>>(), [0xb]);
+    assert_eq!(right.collect::<Vec<_>>(), [0x3, 0xc]);
+
+    let mut nibbles = nibbles;
+    assert_eq!(nibbles.nth(1), Some(0xa));
+    assert_eq!(nibbles.nth_back(1), Some(0x3));
+    assert_eq!(nibbles.len(), 2);
+    assert_eq!(nibbles.nth(5), None);
+    assert_eq!(nibbles.next_back(), None);
+
+    let data = [0x5a; 300];
+    assert_eq!(Nibbles::new(&data).to_string(), "5a".repeat(300));
+    assert_eq!(Nibbles::new(&[]).to_string(), "");
+}
+
+#[test]
+#[should_panic]
+fn should_panic_splitting_nibbles_out_of_bounds() {
+    based16::Nibbles::new(&[0x1a]).split_at(3);
+}
+
+#[test]
+fn should_validate_hex() {
+    use based16::{find_invalid, is_hex, validate, hex_case, DecodeError, DecodeErrorAt};
+
+    let input = "0123456789abcdefABCDEF0123456789abcdef0123456789".repeat(3);
+    assert!(is_hex(input.as_bytes()));
+    assert!(is_hex(b""));
+    assert!(is_hex(b"abc"));
+    assert_eq!(validate(input.as_bytes()), Ok(()));
+    assert_eq!(find_invalid(input.as_bytes()), None);
+
+    for offset in 0..input.len() {
+        for invalid in [b'g', b'G', b'/', b':', b'@', b'`', b' ', 0x80, 0xff] {
+            let mut input = input.clone().into_bytes();
+            input[offset] = invalid;
+            //Second invalid character must not affect result
+            if let Some(ch) = input.get_mut(offset + 1) {
+                *ch = b'z';
+            }
+
+            assert!(!is_hex(&input));
+            assert_eq!(find_invalid(&input), Some(offset));
+            let expected = DecodeErrorAt {
+                offset,
+                error: DecodeError::InvalidChar(invalid),
+            };
+            assert_eq!(validate(&input), Err(expected));
+            assert_eq!(hex_case(&input), Err(expected));
+        }
+    }
+}
+
+#[test]
+fn should_classify_hex_case() {
+    use based16::{hex_case, Case};
+
+    assert_eq!(hex_case(b""), Ok(Case::DigitsOnly));
+    assert_eq!(hex_case(b"0123456789"), Ok(Case::DigitsOnly));
+    assert_eq!(hex_case(b"0123456789abcdef"), Ok(Case::Lower));
+    assert_eq!(hex_case(b"0123456789ABCDEF"), Ok(Case::Upper));
+    assert_eq!(hex_case(b"0123456789abcdeF"), Ok(Case::Mixed));
+
+    //Letters are detected both in SIMD chunks and remainder
+    let digits = "0123456789".repeat(5);
+    for offset in 0..digits.len() {
+        for (letters, expected) in [("a", Case::Lower), ("F", Case::Upper), ("fA", Case::Mixed)] {
+            let mut input = digits.clone();
+            input.insert_str(offset, letters);
+            assert_eq!(hex_case(input.as_bytes()), Ok(expected), "input={}", input);
+        }
+    }
+}
+
+#[test]
+fn should_report_exact_invalid_char() {
+    let mut out = [mem::MaybeUninit::uninit(); 64];
+    let input = "00".repeat(64);
+    for offset in 0..input.len() {
+        let mut input = input.clone().into_bytes();
+        input[offset] = b'x';
+        assert_eq!(unhex(&input, &mut out), Err(based16::DecodeError::InvalidChar(b'x')));
+    }
+}
+
+#[test]
+fn should_change_hex_case() {
+    use based16::{to_lower_in_place, to_upper_in_place, to_lower, to_upper, DecodeError, DecodeErrorAt};
+
+    let input = "0123456789abcdefABCDEF0123456789abCDef".repeat(3);
+    let lower = input.to_ascii_lowercase();
+    let upper = input.to_ascii_uppercase();
+
+    let mut buffer = input.clone().into_bytes();
+    assert_eq!(to_lower_in_place(&mut buffer).expect("to lower case").to_owned(), lower);
+    let mut buffer = input.clone().into_bytes();
+    assert_eq!(to_upper_in_place(&mut buffer).expect("to upper case").to_owned(), upper);
+
+    let mut out = [mem::MaybeUninit::uninit(); 128];
+    let len = to_lower(input.as_bytes(), &mut out).expect("to lower case");
+    assert_eq!(as_bytes(&out, len), lower.as_bytes());
+    let len = to_upper(input.as_bytes(), &mut out).expect("to upper case");
+    assert_eq!(as_bytes(&out, len), upper.as_bytes());
+
+    //Output is truncated without validating the rest
+    let mut out = [mem::MaybeUninit::uninit(); 3];
+    assert_eq!(to_upper(b"abcxyz", &mut out), Ok(3));
+    assert_eq!(as_bytes(&out, 3), b"ABC");
+
+    let mut empty: [u8; 0] = [];
+    assert_eq!(to_lower_in_place(&mut empty).expect("to lower case"), "");
+
+    let mut out = [mem::MaybeUninit::uninit(); 128];
+    for offset in 0..input.len() {
+        for invalid in [b'g', b'G', b'/', b':', b'@', b'`', b' ', 0x80, 0xff] {
+            let mut input = input.clone().into_bytes();
+            input[offset] = invalid;
+            let expected = DecodeErrorAt {
+                offset,
+                error: DecodeError::InvalidChar(invalid),
+            };
+
+            assert_eq!(to_lower(&input, &mut out), Err(expected));
+            assert_eq!(to_upper(&input, &mut out), Err(expected));
+
+            let mut buffer = input.clone();
+            assert_eq!(to_lower_in_place(&mut buffer), Err(expected));
+            //Characters before invalid one are converted, while the rest are intact
+            assert_eq!(&buffer[..offset], &lower.as_bytes()[..offset]);
+            assert_eq!(&buffer[offset..], &input[offset..]);
+        }
+    }
+}
+
+#[test]
+fn should_compare_bytes_with_hex() {
+    use based16::{cmp_hex, eq_hex, ct_eq_hex, DecodeError, DecodeErrorAt};
+    use core::cmp::Ordering;
+
+    let bytes = [0x01, 0xab, 0xcd, 0xef];
+    for hex in [&b"01abcdef"[..], b"01ABCDEF", b"01aBcDeF"] {
+        assert!(eq_hex(&bytes, hex));
+        assert!(ct_eq_hex(&bytes, hex));
+        assert_eq!(cmp_hex(&bytes, hex), Ok(Ordering::Equal));
+    }
+
+    assert!(eq_hex(&[], b""));
+    assert!(ct_eq_hex(&[], b""));
+
+    //Invalid character after mismatch
+    for hex in [&b"11abcdxf"[..], b"11ABCDXF", b"01abcddG", b"01ABCDDg"] {
+        assert!(!eq_hex(&bytes, hex), "hex={:?}", hex);
+        assert!(!ct_eq_hex(&bytes, hex), "hex={:?}", hex);
+    }
+
+    for hex in [&b"01abcdee"[..], b"11abcdef", b"01abcde", b"01abcdef00", b"", b"01abcdeg", b"0gabcdef", b"01abcd\xef"] {
+        assert!(!eq_hex(&bytes, hex), "hex={:?}", hex);
+        assert!(!ct_eq_hex(&bytes, hex), "hex={:?}", hex);
+    }
+
+    //Every character is decoded as expected
+    for byte in 0..=u8::MAX {
+        for hex in [format!("{:02x}", byte), format!("{:02X}", byte)] {
+            assert!(ct_eq_hex(&[byte], hex.as_bytes()));
+            assert!(!ct_eq_hex(&[byte.wrapping_add(1)], hex.as_bytes()));
+        }
+    }
+    for ch in 0..=u8::MAX {
+        let is_valid = ch.is_ascii_hexdigit();
+        assert_eq!(ct_eq_hex(&[0], &[ch, b'0']), ch == b'0');
+        assert_eq!(ct_eq_hex(&[0], &[b'0', ch]), ch == b'0');
+        assert_eq!(cmp_hex(&[0], &[b'0', ch]).is_ok(), is_valid);
+    }
+
+    assert_eq!(cmp_hex(&bytes, b"01abcdee"), Ok(Ordering::Greater));
+    assert_eq!(cmp_hex(&bytes, b"01abcdf0"), Ok(Ordering::Less));
+    assert_eq!(cmp_hex(&bytes, b"01ABCD"), Ok(Ordering::Greater));
+    assert_eq!(cmp_hex(&bytes, b"01abcdef00"), Ok(Ordering::Less));
+    //Odd length is compared by nibbles
+    assert_eq!(cmp_hex(&bytes, b"01abcde"), Ok(Ordering::Greater));
+    assert_eq!(cmp_hex(&bytes, b"01abcdf"), Ok(Ordering::Less));
+    //Characters after difference are not validated
+    assert_eq!(cmp_hex(&bytes, b"02xx"), Ok(Ordering::Less));
+    assert_eq!(cmp_hex(&bytes, b"01axcdef"), Err(DecodeErrorAt {
+        offset: 3,
+        error: DecodeError::InvalidChar(b'x'),
+    }));
+}
+
+#[test]
+fn should_fail_checked_hex_on_insufficient_output() {
+    use based16::{checked_required_encode_len, try_hex_lower, try_hex_upper, try_unhex, OutputTooSmall, TryDecodeError, DecodeError, DecodeErrorAt};
+
+    assert_eq!(checked_required_encode_len(3), Some(6));
+    assert_eq!(checked_required_encode_len(usize::MAX), None);
+    assert_eq!(based16::required_encode_len(usize::MAX), usize::MAX);
+
+    let input = [0xab; 20];
+    let mut out = [mem::MaybeUninit::uninit(); 41];
+    assert_eq!(try_hex_lower(&input, &mut out), Ok(40));
+    assert_eq!(&out[..40].iter().map(|ch| unsafe { ch.assume_init() }).collect::<Vec<_>>(), "ab".repeat(20).as_bytes());
+    assert_eq!(try_hex_upper(&input, &mut out[..40]), Ok(40));
+    assert_eq!(&out[..40].iter().map(|ch| unsafe { ch.assume_init() }).collect::<Vec<_>>(), "AB".repeat(20).as_bytes());
+    //Unlike hex_lower, odd length output doesn't lose byte silently
+    assert_eq!(try_hex_lower(&input, &mut out[..39]), Err(OutputTooSmall {
+        required: 40,
+        actual: 39,
+    }));
+    assert_eq!(try_hex_upper(&input, &mut out[..0]), Err(OutputTooSmall {
+        required: 40,
+        actual: 0,
+    }));
+    assert_eq!(try_hex_lower(&[], &mut out[..0]), Ok(0));
+
+    let hex = "AbCdEf".repeat(8);
+    let mut out = [mem::MaybeUninit::uninit(); 25];
+    assert_eq!(try_unhex(hex.as_bytes(), &mut out), Ok(24));
+    assert_eq!(&out[..24].iter().map(|ch| unsafe { ch.assume_init() }).collect::<Vec<_>>(), &[0xab, 0xcd, 0xef].repeat(8));
+    assert_eq!(try_unhex(hex.as_bytes(), &mut out[..23]), Err(TryDecodeError::OutputTooSmall(OutputTooSmall {
+        required: 24,
+        actual: 23,
+    })));
+    assert_eq!(try_unhex(&hex.as_bytes()[..47], &mut out), Err(TryDecodeError::OddLength(47)));
+    assert_eq!(try_unhex(b"", &mut out[..0]), Ok(0));
+
+    for offset in 0..hex.len() {
+        let mut hex = hex.clone().into_bytes();
+        hex[offset] = b'x';
+        assert_eq!(try_unhex(&hex, &mut out), Err(TryDecodeError::InvalidChar(DecodeErrorAt {
+            offset,
+            error: DecodeError::InvalidChar(b'x'),
+        })));
+    }
+
+    assert_eq!(TryDecodeError::OddLength(3).to_string(), "Input has odd length 3");
+    assert_eq!(OutputTooSmall { required: 4, actual: 3 }.to_string(), "Output of 3 bytes is too small, required 4");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn should_implement_std_error() {
+    fn assert_error<E: std::error::Error>() {}
+
+    assert_error::<based16::DecodeError>();
+    assert_error::<based16::DecodeErrorAt>();
+    assert_error::<based16::AlphabetError>();
+    assert_error::<based16::modhex::OtpError>();
+    assert_error::<based16::percent::MalformedEscape>();
+    assert_error::<based16::escape::InvalidEscape>();
+    assert_error::<based16::quoted_printable::MalformedEscape>();
+    assert_error::<based16::uuid::UuidError>();
+    assert_error::<based16::eui::EuiError>();
+    assert_error::<based16::color::ColorError>();
+    assert_error::<based16::pattern::PatternError>();
+    assert_error::<based16::OutputTooSmall>();
+    assert_error::<based16::TryDecodeError>();
+
+    let error = std::io::Error::from(based16::DecodeError::InvalidChar(b'x'));
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,97 @@
+#![cfg(any(feature = "tokio", feature = "futures-io"))]
+
+use based16::{HexEncodeWriter, HexDecodeReader};
+
+const INPUT: &[u8] = b"\x00\x01\x7f\x80\xfe\xffbased16 for chads!";
+const INPUT_LOWER: &str = "00017f80feff6261736564313620666f7220636861647321";
+const INPUT_UPPER: &str = "00017F80FEFF6261736564313620666F7220636861647321";
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn should_encode_into_tokio_duplex() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut big = Vec::new();
+    for idx in 0..10_000 {
+        big.push(idx as u8);
+    }
+    let expected = based16::Encoder::lower(&big).to_string();
+
+    //Small capacity forces partial writes into inner writer
+    let (client, mut server) = tokio::io::duplex(3);
+    let writer = tokio::spawn(async move {
+        let mut writer = HexEncodeWriter::lower(client);
+        writer.write_all(&big).await.expect("to write");
+        writer.shutdown().await.expect("to shutdown");
+    });
+
+    let mut output = String::new();
+    server.read_to_string(&mut output).await.expect("to read");
+    writer.await.expect("to finish writing");
+    assert_eq!(output, expected);
+
+    let mut writer = HexEncodeWriter::upper(Vec::new());
+    writer.write_all(INPUT).await.expect("to write");
+    assert_eq!(writer.buffer(), INPUT_UPPER.as_bytes());
+    writer.flush().await.expect("to flush");
+    assert!(writer.buffer().is_empty());
+    assert_eq!(writer.into_inner(), INPUT_UPPER.as_bytes());
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn should_decode_from_tokio_duplex() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    //Odd sized writes split pairs across reads
+    let (mut client, server) = tokio::io::duplex(3);
+    let writer = tokio::spawn(async move {
+        for chunk in INPUT_LOWER.as_bytes().chunks(5) {
+            client.write_all(chunk).await.expect("to write");
+        }
+    });
+
+    let mut reader = HexDecodeReader::new(server);
+    let mut output = Vec::new();
+    reader.read_to_end(&mut output).await.expect("to read");
+    writer.await.expect("to finish writing");
+    assert_eq!(output, INPUT);
+
+    let mut reader = HexDecodeReader::new(INPUT_UPPER.as_bytes());
+    let mut output = [0u8; 3];
+    reader.read_exact(&mut output).await.expect("to read");
+    assert_eq!(output, INPUT[..3]);
+
+    let mut reader = HexDecodeReader::new(&b"abc"[..]);
+    let error = reader.read_to_end(&mut Vec::new()).await.expect_err("to fail");
+    assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+
+    let mut reader = HexDecodeReader::new(&b"abzz"[..]);
+    let error = reader.read_to_end(&mut Vec::new()).await.expect_err("to fail");
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn should_encode_and_decode_futures_io() {
+    use futures::io::{AsyncReadExt, AsyncWriteExt, Cursor};
+
+    futures::executor::block_on(async {
+        let mut writer = HexEncodeWriter::lower(Cursor::new(Vec::new()));
+        for chunk in INPUT.chunks(3) {
+            writer.write_all(chunk).await.expect("to write");
+        }
+        writer.close().await.expect("to close");
+        assert_eq!(writer.into_inner().into_inner(), INPUT_LOWER.as_bytes());
+
+        let (first, second) = INPUT_UPPER.as_bytes().split_at(7);
+        let mut reader = HexDecodeReader::new(Cursor::new(first).chain(Cursor::new(second)));
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).await.expect("to read");
+        assert_eq!(output, INPUT);
+
+        let mut reader = HexDecodeReader::new(Cursor::new(&b"0g"[..]));
+        let error = reader.read_to_end(&mut Vec::new()).await.expect_err("to fail");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    });
+}
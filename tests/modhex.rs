@@ -0,0 +1,70 @@
+use based16::modhex::{self, Otp, OtpError};
+use based16::DecodeError;
+
+use core::mem;
+
+mod common;
+use common::as_bytes;
+
+//Example OTP from Yubico documentation
+const OTP: &str = "ccccccjlkgjfhrinrbfnfkkiggdbvdhgvlrdnhfugrhh";
+
+#[test]
+fn should_encode_and_decode_modhex() {
+    let input = [0x00, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xff, 0x10, 0x32, 0x54, 0x76, 0x98, 0xba, 0xdc, 0xfe];
+    let expected = "cccbdefghijklnrtuvvvbcedgfihkjnltrvu";
+
+    let mut encoded = [mem::MaybeUninit::uninit(); 36];
+    let len = modhex::encode(&input, &mut encoded);
+    assert_eq!(as_bytes(&encoded, len), expected.as_bytes());
+    assert_eq!(modhex::encoder(&input).to_string(), expected);
+    assert_eq!(format!("{:.2}", modhex::encoder(&input)), "cccb...");
+
+    let mut decoded = [mem::MaybeUninit::uninit(); 18];
+    let len = modhex::decode(expected.as_bytes(), &mut decoded).expect("to decode");
+    assert_eq!(as_bytes(&decoded, len), input);
+    let decoded = modhex::decoder(expected).expect("even length").collect::<Result<Vec<_>, _>>().expect("to decode");
+    assert_eq!(decoded, input);
+
+    assert!(modhex::decoder("cbd").is_none());
+    assert_eq!(modhex::decode(b"cbDE", &mut [mem::MaybeUninit::uninit(); 2]), Err(DecodeError::InvalidChar(b'D')));
+    assert_eq!(modhex::decoder("ca").expect("even length").next(), Some(Err(DecodeError::InvalidChar(b'a'))));
+}
+
+#[test]
+fn should_convert_between_modhex_and_hex() {
+    let mut out = [mem::MaybeUninit::uninit(); 16];
+
+    let len = modhex::to_hex(b"cbdefghijklnrtuvc", &mut out).expect("to convert");
+    assert_eq!(as_bytes(&out, len), b"0123456789abcdef");
+    let len = modhex::to_hex(b"vvc", &mut out).expect("to convert");
+    assert_eq!(as_bytes(&out, len), b"ff0");
+    assert_eq!(modhex::to_hex(b"cba", &mut out), Err(DecodeError::InvalidChar(b'a')));
+
+    let len = modhex::from_hex(b"0123456789ABCDEF", &mut out).expect("to convert");
+    assert_eq!(as_bytes(&out, len), b"cbdefghijklnrtuv");
+    let len = modhex::from_hex(b"fFa", &mut out).expect("to convert");
+    assert_eq!(as_bytes(&out, len), b"vvl");
+    assert_eq!(modhex::from_hex(b"0g", &mut out), Err(DecodeError::InvalidChar(b'g')));
+}
+
+#[test]
+fn should_parse_otp() {
+    let otp = Otp::parse(OTP).expect("valid OTP");
+    assert_eq!(otp.public_id(), "ccccccjlkgjf");
+    assert_eq!(otp.token(), "hrinrbfnfkkiggdbvdhgvlrdnhfugrhh");
+
+    let mut public_id = [mem::MaybeUninit::uninit(); 16];
+    let len = otp.decode_public_id(&mut public_id);
+    assert_eq!(as_bytes(&public_id, len), [0x00, 0x00, 0x00, 0x8a, 0x95, 0x84]);
+    assert_eq!(otp.decode_token(), [0x6c, 0x7b, 0xc1, 0x4b, 0x49, 0x97, 0x55, 0x21, 0xf2, 0x65, 0xfa, 0xc2, 0xb6, 0x4e, 0x5c, 0x66]);
+
+    let otp = Otp::parse(&OTP[12..]).expect("valid OTP without public id");
+    assert_eq!(otp.public_id(), "");
+    assert_eq!(otp.decode_public_id(&mut public_id), 0);
+
+    assert_eq!(Otp::parse(&OTP[1..]), Err(OtpError::InvalidLength(43)));
+    assert_eq!(Otp::parse(&OTP[14..]), Err(OtpError::InvalidLength(30)));
+    assert_eq!(Otp::parse(&"c".repeat(66)), Err(OtpError::InvalidLength(66)));
+    assert_eq!(Otp::parse("ccccccjlkgjfhrinrbfnfkkiggdbvdhgvlrdnhfugrha"), Err(OtpError::InvalidChar(DecodeError::InvalidChar(b'a'))));
+}
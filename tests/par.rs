@@ -0,0 +1,69 @@
+#![cfg(feature = "rayon")]
+
+use based16::{par_hex_lower, par_hex_upper, par_unhex, DecodeError, DecodeErrorAt, Encoder};
+
+use core::mem;
+
+mod common;
+use common::as_bytes;
+
+fn input(len: usize) -> Vec<u8> {
+    (0..len).map(|idx| (idx * 7) as u8).collect()
+}
+
+#[test]
+fn should_encode_in_parallel() {
+    for len in [0, 1, 17, 64 * 1024, 64 * 1024 + 1, 300_001] {
+        let input = input(len);
+        let mut out = vec![mem::MaybeUninit::uninit(); len * 2];
+
+        let written = par_hex_lower(&input, &mut out);
+        assert_eq!(written, len * 2);
+        assert_eq!(as_bytes(&out, written), Encoder::lower(&input).to_string().as_bytes());
+
+        let written = par_hex_upper(&input, &mut out);
+        assert_eq!(written, len * 2);
+        assert_eq!(as_bytes(&out, written), Encoder::upper(&input).to_string().as_bytes());
+
+        //Truncates by output size
+        if len > 0 {
+            let written = par_hex_lower(&input, &mut out[..len * 2 - 1]);
+            assert_eq!(written, len * 2 - 2);
+            assert_eq!(as_bytes(&out, written), Encoder::lower(&input[..len - 1]).to_string().as_bytes());
+        }
+    }
+}
+
+#[test]
+fn should_decode_in_parallel() {
+    for len in [0, 1, 17, 64 * 1024, 64 * 1024 + 1, 300_001] {
+        let input = input(len);
+        let encoded = Encoder::upper(&input).to_string();
+        let mut out = vec![mem::MaybeUninit::uninit(); len];
+
+        let written = par_unhex(encoded.as_bytes(), &mut out).expect("to decode");
+        assert_eq!(written, len);
+        assert_eq!(as_bytes(&out, written), input);
+
+        let written = par_unhex(encoded.as_bytes(), &mut out[..len / 2]).expect("to decode");
+        assert_eq!(written, len / 2);
+        assert_eq!(as_bytes(&out, written), &input[..len / 2]);
+    }
+}
+
+#[test]
+fn should_report_earliest_error_in_parallel() {
+    let mut encoded = Encoder::lower(&input(400_000)).to_string().into_bytes();
+    let mut out = vec![mem::MaybeUninit::uninit(); 400_000];
+    for offset in [700_001, 500_000, 131_073, 131_072, 3] {
+        encoded[offset] = b'z';
+        let error = par_unhex(&encoded, &mut out).expect_err("to fail");
+        assert_eq!(error, DecodeErrorAt {
+            offset,
+            error: DecodeError::InvalidChar(b'z'),
+        });
+    }
+    assert_eq!(par_unhex(&encoded, &mut out[..1]).expect("to decode"), 1);
+    assert_eq!(par_unhex(&encoded, &mut out[..2]).expect_err("to fail").offset, 3);
+    assert_eq!(par_unhex(&encoded[4..], &mut out).expect_err("to fail").offset, 131_068);
+}
@@ -0,0 +1,74 @@
+use based16::pattern::{Pattern, PatternError};
+use based16::{DecodeError, DecodeErrorAt};
+
+const CALL: Pattern<16> = Pattern::from_literal("48 8B ?? ?? E8 ?? ?? ?? ??");
+
+#[test]
+fn should_parse_pattern() {
+    assert_eq!(CALL.len(), 9);
+    assert_eq!(CALL.to_string(), "48 8B ?? ?? E8 ?? ?? ?? ??");
+    assert_eq!(format!("{:?}", CALL), "Pattern(48 8B ?? ?? E8 ?? ?? ?? ??)");
+
+    let pattern: Pattern<4> = "\t4?8b ?F\n".parse().expect("to parse");
+    assert_eq!(pattern.len(), 3);
+    assert_eq!(pattern.to_string(), "4? 8B ?F");
+    assert_eq!(pattern, Pattern::<4>::parse("4?8B?f").expect("to parse"));
+
+    let empty = Pattern::<4>::parse("  ").expect("to parse");
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_string(), "");
+
+    assert_eq!(Pattern::<2>::parse("00 11 22"), Err(PatternError::TooLong(2)));
+    assert_eq!(Pattern::<4>::parse("00 1 22"), Err(PatternError::IncompleteByte(3)));
+    assert_eq!(Pattern::<4>::parse("00 11 2"), Err(PatternError::IncompleteByte(6)));
+    assert_eq!(Pattern::<4>::parse("00 1x"), Err(PatternError::InvalidChar(DecodeErrorAt {
+        offset: 4,
+        error: DecodeError::InvalidChar(b'x'),
+    })));
+    assert_eq!(Pattern::<4>::parse("00 *?"), Err(PatternError::InvalidChar(DecodeErrorAt {
+        offset: 3,
+        error: DecodeError::InvalidChar(b'*'),
+    })));
+    assert_eq!(PatternError::TooLong(2).to_string(), "Pattern exceeds capacity of 2 bytes");
+    assert_eq!(PatternError::IncompleteByte(3).to_string(), "Incomplete byte at offset 3");
+}
+
+#[test]
+fn should_match_pattern() {
+    let code = [0x90, 0x48, 0x8b, 0x05, 0x10, 0xe8, 0x01, 0x02, 0x03, 0x04, 0x48, 0x8b, 0x00, 0x00, 0xe8, 0xff, 0xff, 0xff, 0xff];
+    assert!(CALL.is_prefix_of(&code[1..]));
+    assert!(!CALL.is_prefix_of(&code));
+    assert!(!CALL.is_prefix_of(&code[1..9]));
+    assert_eq!(CALL.find(&code), Some(1));
+    assert_eq!(CALL.find_iter(&code).collect::<Vec<_>>(), [1, 10]);
+    assert_eq!(CALL.find(&code[..18]), Some(1));
+    assert_eq!(CALL.find(&code[2..18]), None);
+
+    //Nibble wildcards
+    let pattern = Pattern::<4>::from_literal("4? ?b");
+    assert_eq!(pattern.find_iter(&code).collect::<Vec<_>>(), [1, 10]);
+    assert_eq!(pattern.find_iter(&[0x40, 0x0b, 0x4f, 0xfb, 0x50, 0x0b]).collect::<Vec<_>>(), [0, 2]);
+
+    //Overlapping matches
+    let pattern = Pattern::<4>::from_literal("aa ?? aa");
+    assert_eq!(pattern.find_iter(&[0xaa; 6]).collect::<Vec<_>>(), [0, 1, 2, 3]);
+
+    //Without fully specified bytes
+    let pattern = Pattern::<4>::from_literal("?? ?1");
+    assert_eq!(pattern.find_iter(&[0x01, 0x11, 0x21, 0x02]).collect::<Vec<_>>(), [0, 1]);
+
+    let empty = Pattern::<4>::from_literal("");
+    assert_eq!(empty.find_iter(&[1, 2]).collect::<Vec<_>>(), [0, 1, 2]);
+
+    //Candidates are found both in SIMD chunks and remainder
+    let pattern = Pattern::<4>::from_literal("?? 5a ?5");
+    for offset in 0..64 {
+        let mut data = [0u8; 67];
+        data[offset] = 0xff;
+        data[offset + 1] = 0x5a;
+        data[offset + 2] = 0x15;
+        assert_eq!(pattern.find(&data), Some(offset));
+        assert_eq!(pattern.find_iter(&data).count(), 1);
+        assert_eq!(pattern.find(&data[..offset + 2]), None);
+    }
+}
@@ -0,0 +1,102 @@
+use based16::percent::{self, AsciiSet, MalformedEscape, COMPONENT, CONTROLS, FORM, FRAGMENT, NON_ALPHANUMERIC, PATH, QUERY, USERINFO};
+
+use core::mem;
+
+mod common;
+use common::as_bytes;
+
+#[test]
+fn should_build_ascii_sets() {
+    const SET: AsciiSet = AsciiSet::EMPTY.add(b'a').add(b'~').add(0x7f);
+
+    assert!(SET.contains(b'a'));
+    assert!(SET.contains(b'~'));
+    assert!(SET.contains(0x7f));
+    assert!(!SET.contains(b'b'));
+    assert!(!SET.contains(0xe1));
+    assert!(!SET.remove(b'a').contains(b'a'));
+    assert_eq!(SET.remove(0xe1), SET);
+    assert_eq!(AsciiSet::EMPTY.add(b'b').union(&SET), SET.add(b'b'));
+
+    for ch in 0..=0x7fu8 {
+        assert_eq!(NON_ALPHANUMERIC.contains(ch), !ch.is_ascii_alphanumeric(), "ch={}", ch);
+        assert_eq!(CONTROLS.contains(ch), ch.is_ascii_control(), "ch={}", ch);
+    }
+
+    assert!(FRAGMENT.contains(b'`') && !FRAGMENT.contains(b'#'));
+    assert!(QUERY.contains(b'#') && !QUERY.contains(b'?'));
+    assert!(PATH.contains(b'?') && !PATH.contains(b'/'));
+    assert!(USERINFO.contains(b'/') && !USERINFO.contains(b'%'));
+    assert!(COMPONENT.contains(b'%') && !COMPONENT.contains(b'!'));
+    assert!(FORM.contains(b'!') && !FORM.contains(b'*'));
+}
+
+#[test]
+fn should_percent_encode() {
+    let input = "a b/ä%+*".as_bytes();
+    let mut out = [mem::MaybeUninit::uninit(); 24];
+    assert_eq!(percent::required_encode_len(input.len()), 27);
+
+    let len = percent::encode(input, &COMPONENT, &mut out);
+    assert_eq!(as_bytes(&out, len), b"a%20b%2F%C3%A4%25%2B*");
+    let len = percent::encode(input, &CONTROLS, &mut out);
+    assert_eq!(as_bytes(&out, len), b"a b/%C3%A4%+*");
+    let len = percent::encode_form(input, &mut out);
+    assert_eq!(as_bytes(&out, len), b"a+b%2F%C3%A4%25%2B*");
+
+    //Escape sequence is never split
+    let len = percent::encode(input, &COMPONENT, &mut out[..3]);
+    assert_eq!(as_bytes(&out, len), b"a");
+    let len = percent::encode(input, &COMPONENT, &mut out[..4]);
+    assert_eq!(as_bytes(&out, len), b"a%20");
+
+    assert_eq!(percent::Encoder::new(input, &COMPONENT).to_string(), "a%20b%2F%C3%A4%25%2B*");
+    assert_eq!(percent::Encoder::form(input).to_string(), "a+b%2F%C3%A4%25%2B*");
+    assert_eq!(format!("{:?}", percent::Encoder::form(b"a b")), "\"a+b\"");
+
+    let input = [0xffu8; 1000];
+    let encoded = percent::Encoder::new(&input, &AsciiSet::EMPTY).to_string();
+    assert_eq!(encoded, "%FF".repeat(1000));
+}
+
+#[test]
+fn should_percent_decode() {
+    let mut out = [mem::MaybeUninit::uninit(); 32];
+
+    let len = percent::decode(b"a%20b%2f%C3%A4+", &mut out).expect("to decode");
+    assert_eq!(as_bytes(&out, len), "a b/ä+".as_bytes());
+    let len = percent::decode_form(b"a+b%2B", &mut out).expect("to decode");
+    assert_eq!(as_bytes(&out, len), b"a b+");
+    let len = percent::decode(b"a%20b", &mut out[..2]).expect("to decode");
+    assert_eq!(as_bytes(&out, len), b"a ");
+
+    assert_eq!(percent::decode(b"ab%2", &mut out), Err(MalformedEscape { offset: 2 }));
+    assert_eq!(percent::decode(b"%zz", &mut out), Err(MalformedEscape { offset: 0 }));
+    assert_eq!(percent::decode_form(b"+%+1", &mut out), Err(MalformedEscape { offset: 1 }));
+    assert_eq!(MalformedEscape { offset: 1 }.to_string(), "Malformed percent escape at offset 1");
+
+    let len = percent::decode_lenient(b"%%41%z1+%4", &mut out);
+    assert_eq!(as_bytes(&out, len), b"%A%z1+%4");
+    let len = percent::decode_form_lenient(b"%%41%z1+%4", &mut out);
+    assert_eq!(as_bytes(&out, len), b"%A%z1 %4");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_percent_encode_and_decode_cow() {
+    use std::borrow::Cow;
+
+    assert!(matches!(percent::encode_to_cow(b"abc", &COMPONENT), Cow::Borrowed("abc")));
+    assert_eq!(percent::encode_to_cow("a ä".as_bytes(), &CONTROLS), "a %C3%A4");
+    assert!(matches!(percent::encode_form_to_cow(b"a*b"), Cow::Borrowed("a*b")));
+    assert_eq!(percent::encode_form_to_cow(b"a b"), "a+b");
+
+    assert!(matches!(percent::decode_to_cow(b"a+b"), Ok(Cow::Borrowed(b"a+b"))));
+    assert_eq!(percent::decode_to_cow(b"a%2Bb").expect("to decode"), &b"a+b"[..]);
+    assert_eq!(percent::decode_to_cow(b"a%"), Err(MalformedEscape { offset: 1 }));
+    assert_eq!(percent::decode_lenient_to_cow(b"a%"), &b"a%"[..]);
+    assert!(matches!(percent::decode_form_to_cow(b"ab"), Ok(Cow::Borrowed(b"ab"))));
+    assert_eq!(percent::decode_form_to_cow(b"a+b").expect("to decode"), &b"a b"[..]);
+    assert_eq!(percent::decode_form_to_cow(b"a+%g"), Err(MalformedEscape { offset: 2 }));
+    assert_eq!(percent::decode_form_lenient_to_cow(b"a+%g"), &b"a %g"[..]);
+}
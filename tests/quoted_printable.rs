@@ -0,0 +1,84 @@
+use based16::quoted_printable::{self, Encoder, MalformedEscape, MAX_LINE_LEN};
+
+use core::mem;
+
+mod common;
+use common::as_bytes;
+
+fn decode(input: &[u8]) -> Result<Vec<u8>, MalformedEscape> {
+    let mut out = vec![mem::MaybeUninit::uninit(); input.len()];
+    let len = quoted_printable::decode(input, &mut out)?;
+    Ok(as_bytes(&out, len).to_vec())
+}
+
+#[test]
+fn should_encode_quoted_printable() {
+    assert_eq!(Encoder::new("a=b ü\tc \r\nd\t\r\n\n ".as_bytes()).to_string(), "a=3Db =C3=BC\tc=20\r\nd=09\r\n=0A=20");
+
+    let mut out = [mem::MaybeUninit::uninit(); 16];
+    let len = quoted_printable::encode(b"a=b ", &mut out);
+    assert_eq!(as_bytes(&out, len), b"a=3Db=20");
+    //Escape sequence is never split
+    let len = quoted_printable::encode(b"a=b", &mut out[..3]);
+    assert_eq!(as_bytes(&out, len), b"a");
+}
+
+#[test]
+fn should_break_long_lines() {
+    let input = "x".repeat(200);
+    let encoded = Encoder::new(input.as_bytes()).to_string();
+    let lines = encoded.split("\r\n").collect::<Vec<_>>();
+    assert_eq!(lines, ["x".repeat(75) + "=", "x".repeat(75) + "=", "x".repeat(50)]);
+
+    //Last character of line may take place of soft line break
+    let input = "x".repeat(76) + "\r\n" + &"x".repeat(77);
+    let encoded = Encoder::new(input.as_bytes()).to_string();
+    let lines = encoded.split("\r\n").collect::<Vec<_>>();
+    assert_eq!(lines, ["x".repeat(76), "x".repeat(75) + "=", "xx".to_owned()]);
+
+    let input = "x".repeat(74) + "==";
+    let encoded = Encoder::new(input.as_bytes()).to_string();
+    assert_eq!(encoded, "x".repeat(74) + "=\r\n=3D=3D");
+
+    let input = (0..=255u8).cycle().take(10_000).collect::<Vec<_>>();
+    let mut out = vec![mem::MaybeUninit::uninit(); quoted_printable::required_encode_len(input.len())];
+    let len = quoted_printable::encode(&input, &mut out);
+    let encoded = as_bytes(&out, len);
+    assert_eq!(encoded, Encoder::new(&input).to_string().as_bytes());
+    for line in encoded.split(|ch| *ch == b'\n') {
+        assert!(line.len() <= MAX_LINE_LEN + 1);
+    }
+    assert_eq!(decode(encoded).expect("to decode"), input);
+}
+
+#[test]
+fn should_decode_quoted_printable() {
+    assert_eq!(decode(b"a=3db=20 \t\r\nc =\r\nd= \t\ne  \nf=").expect("to decode"), b"a=b \r\nc de\nf");
+    assert_eq!(decode(b"a \t b \t").expect("to decode"), b"a \t b");
+
+    let mut out = [mem::MaybeUninit::uninit(); 2];
+    let len = quoted_printable::decode(b"a=3Db", &mut out).expect("to decode");
+    assert_eq!(as_bytes(&out, len), b"a=");
+
+    assert_eq!(decode(b"ab=4"), Err(MalformedEscape { offset: 2 }));
+    assert_eq!(decode(b"ab=zz"), Err(MalformedEscape { offset: 2 }));
+    assert_eq!(decode(b"a= b"), Err(MalformedEscape { offset: 1 }));
+    assert_eq!(MalformedEscape { offset: 1 }.to_string(), "Malformed quoted-printable escape at offset 1");
+}
+
+#[test]
+fn should_encode_and_decode_q() {
+    let input = "Keld Jørn_Simonsen?=!".as_bytes();
+    let encoded = Encoder::q(input).to_string();
+    assert_eq!(encoded, "Keld_J=C3=B8rn=5FSimonsen=3F=3D!");
+
+    let mut out = [mem::MaybeUninit::uninit(); 64];
+    let len = quoted_printable::encode_q(input, &mut out);
+    assert_eq!(as_bytes(&out, len), encoded.as_bytes());
+    let len = quoted_printable::encode_q(input, &mut out[..7]);
+    assert_eq!(as_bytes(&out, len), b"Keld_J");
+
+    let len = quoted_printable::decode_q(encoded.as_bytes(), &mut out).expect("to decode");
+    assert_eq!(as_bytes(&out, len), input);
+    assert_eq!(quoted_printable::decode_q(b"a=\r\n", &mut out), Err(MalformedEscape { offset: 1 }));
+}
@@ -0,0 +1,59 @@
+use based16::uuid::{self, Format, UuidError, BRACED_LEN, HYPHENATED_LEN, SIMPLE_LEN, URN_LEN};
+use based16::{DecodeError, DecodeErrorAt};
+
+const UUID: [u8; 16] = [0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8];
+
+#[test]
+fn should_encode_uuid() {
+    let text = uuid::encode_lower(&UUID, Format::Hyphenated);
+    assert_eq!(text.as_str(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    assert_eq!(text.len(), HYPHENATED_LEN);
+    assert_eq!(format!("{:?}", text), "\"67e55044-10b1-426f-9247-bb680e5fe0c8\"");
+
+    let text = uuid::encode_upper(&UUID, Format::Simple);
+    assert_eq!(text.to_string(), "67E5504410B1426F9247BB680E5FE0C8");
+    assert_eq!(text.len(), SIMPLE_LEN);
+
+    let text = uuid::encode_lower(&UUID, Format::Braced);
+    assert_eq!(&*text, "{67e55044-10b1-426f-9247-bb680e5fe0c8}");
+    assert_eq!(text.len(), BRACED_LEN);
+
+    let text = uuid::encode_upper(&UUID, Format::Urn);
+    assert_eq!(text.as_ref(), "urn:uuid:67E55044-10B1-426F-9247-BB680E5FE0C8");
+    assert_eq!(text.len(), URN_LEN);
+}
+
+#[test]
+fn should_decode_uuid() {
+    for format in [Format::Hyphenated, Format::Simple, Format::Braced, Format::Urn] {
+        assert_eq!(uuid::decode(&uuid::encode_lower(&UUID, format)), Ok(UUID));
+        assert_eq!(uuid::decode(&uuid::encode_upper(&UUID, format)), Ok(UUID));
+    }
+    assert_eq!(uuid::decode("URN:UUID:67e55044-10b1-426f-9247-bb680e5fe0c8"), Ok(UUID));
+
+    assert_eq!(uuid::decode(""), Err(UuidError::InvalidLength(0)));
+    assert_eq!(uuid::decode("67e5504410b1426f9247bb680e5fe0c"), Err(UuidError::InvalidLength(31)));
+    assert_eq!(uuid::decode("{67e5504410b1426f9247bb680e5fe0c8"), Err(UuidError::UnclosedBrace));
+    assert_eq!(uuid::decode("67e55044-10b1-426f-9247bb680e5fe0c8"), Err(UuidError::InvalidGroupCount(4)));
+    assert_eq!(uuid::decode("67e55044-10b1-426f-9247-bb68-0e5fe0c8"), Err(UuidError::InvalidGroupCount(6)));
+    assert_eq!(uuid::decode("67e5504-410b1-426f-9247-bb680e5fe0c8"), Err(UuidError::InvalidGroupLength {
+        group: 0,
+        expected: 8,
+        found: 7,
+    }));
+    assert_eq!(uuid::decode("urn:uuid:67e55044-10b1-426f-92470-bb680e5fe0c"), Err(UuidError::InvalidGroupLength {
+        group: 3,
+        expected: 4,
+        found: 5,
+    }));
+    assert_eq!(uuid::decode("{67e55044-10b1-426f-9247-bb680e5fe0cz}"), Err(UuidError::InvalidChar(DecodeErrorAt {
+        offset: 36,
+        error: DecodeError::InvalidChar(b'z'),
+    })));
+    assert_eq!(uuid::decode("67e5504410b1426f9247bb680e5fe0+8"), Err(UuidError::InvalidChar(DecodeErrorAt {
+        offset: 30,
+        error: DecodeError::InvalidChar(b'+'),
+    })));
+
+    assert_eq!(UuidError::InvalidGroupLength { group: 1, expected: 4, found: 3 }.to_string(), "UUID group 1 has 3 characters, expected 4");
+}